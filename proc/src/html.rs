@@ -1,29 +1,313 @@
 use derive_syn_parse::Parse;
 use syn::{braced, Attribute};
-use syn::{Token, Path, Pat, Expr, parse::ParseStream};
+use quote::ToTokens;
+use syn::{Token, Path, Expr, Pat, Ident, LitStr, parse::ParseStream, ext::IdentExt, punctuated::Punctuated, spanned::Spanned};
 use syn::parse::Parse;
 
 #[derive(Debug)]
 pub enum Html {
     Element (Vec<Attribute>, Element),
     Expr (Vec<Attribute>, Expr),
+    /// Bare string literal (i.e. `"Hello world"`)
+    Text (Vec<Attribute>, LitStr),
+    /// Interpolated text (i.e. `{f "Hello {name}"}`)
+    Format (Vec<Attribute>, Format),
+    /// Conditional block (i.e. `if cond { <a/> } else { <b/> }`)
+    If (Vec<Attribute>, HtmlIf),
+    /// Pattern matching block (i.e. `match value { Some(x) => { {x} } None => { "Empty" } }`)
+    Match (Vec<Attribute>, HtmlMatch),
+    /// Loop block (i.e. `for item in &items key={item.id} { <li>{&item.name}</li> }`)
+    For (Vec<Attribute>, HtmlFor),
+    /// Named slot of a component (i.e. `slot header { <h1>"Title"</h1> }`)
+    Slot (Vec<Attribute>, HtmlSlot)
 }
 
 impl Parse for Html {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+       let attrs = Attribute::parse_outer(input)?;
+
+       if input.peek(Token![if]) {
+            return input.parse().map(|x| Self::If(attrs, x))
+       }
+       if input.peek(Token![match]) {
+            return input.parse().map(|x| Self::Match(attrs, x))
+       }
+       if input.peek(Token![for]) {
+            return input.parse().map(|x| Self::For(attrs, x))
+       }
+       if input.peek(Ident) && input.peek2(Ident) && input.peek3(syn::token::Brace) && input.fork().parse::<Ident>()? == "slot" {
+            return input.parse().map(|x| Self::Slot(attrs, x))
+       }
+
        if input.peek(syn::token::Brace) {
-            let attrs = Attribute::parse_outer(input)?;
             let content; braced!(content in input);
+
+            if content.peek(Ident) && content.peek2(LitStr) && content.fork().parse::<Ident>()? == "f" {
+                content.parse::<Ident>()?;
+                let format = Format::parse(&content)?;
+                if !content.is_empty() {
+                    return Err(content.error("unexpected tokens after interpolated text"))
+                }
+                return Ok(Self::Format(attrs, format))
+            }
+
             let tokens = Expr::parse(&content)?;
             return Ok(Self::Expr(attrs, tokens))
        }
 
-       let attrs = Attribute::parse_outer(input)?;
+       if input.peek(LitStr) {
+            return input.parse().map(|x| Self::Text(attrs, x))
+       }
+
        let element = Element::parse(input)?;
        return Ok(Self::Element(attrs, element))
     }
 }
 
+/// Children of a control flow block, delimited by braces
+#[derive(Debug)]
+pub struct Block {
+    pub _brace_token: syn::token::Brace,
+    pub children: Vec<Html>
+}
+
+impl Parse for Block {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _brace_token = braced!(content in input);
+        let Elements(children) = content.parse()?;
+        return Ok(Self { _brace_token, children })
+    }
+}
+
+#[derive(Debug)]
+pub struct HtmlIf {
+    pub if_token: Token![if],
+    pub cond: Expr,
+    pub then: Block,
+    pub otherwise: Option<(Token![else], HtmlElse)>
+}
+
+#[derive(Debug)]
+pub enum HtmlElse {
+    If (Box<HtmlIf>),
+    Block (Block)
+}
+
+impl Parse for HtmlIf {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let if_token = input.parse()?;
+        let cond = Expr::parse_without_eager_brace(input)?;
+        let then = input.parse()?;
+
+        let otherwise = match input.parse::<Option<Token![else]>>()? {
+            Some(else_token) if input.peek(Token![if]) => Some((else_token, HtmlElse::If(Box::new(input.parse()?)))),
+            Some(else_token) => Some((else_token, HtmlElse::Block(input.parse()?))),
+            None => None
+        };
+
+        return Ok(Self { if_token, cond, then, otherwise })
+    }
+}
+
+#[derive(Debug)]
+pub struct HtmlMatch {
+    pub match_token: Token![match],
+    pub expr: Expr,
+    pub arms: Vec<HtmlArm>
+}
+
+impl Parse for HtmlMatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = input.parse()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+
+        let content; braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        return Ok(Self { match_token, expr, arms })
+    }
+}
+
+/// Arm of a `match` block. It's body must always be delimited by braces
+#[derive(Debug)]
+pub struct HtmlArm {
+    pub pat: Pat,
+    pub guard: Option<(Token![if], Expr)>,
+    pub fat_arrow_token: Token![=>],
+    pub body: Block,
+    pub _comma: Option<Token![,]>
+}
+
+impl Parse for HtmlArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let leading_vert = input.parse::<Option<Token![|]>>()?;
+        let mut cases = Punctuated::<Pat, Token![|]>::new();
+        cases.push_value(input.parse()?);
+        while input.peek(Token![|]) {
+            cases.push_punct(input.parse()?);
+            cases.push_value(input.parse()?);
+        }
+
+        let pat = match (leading_vert, cases.len()) {
+            (None, 1) => cases.pop().unwrap().into_value(),
+            _ => Pat::Or(syn::PatOr { attrs: Vec::new(), leading_vert, cases })
+        };
+
+        let guard = match input.parse::<Option<Token![if]>>()? {
+            Some(if_token) => Some((if_token, input.parse()?)),
+            None => None
+        };
+
+        return Ok(Self {
+            pat,
+            guard,
+            fat_arrow_token: input.parse()?,
+            body: input.parse()?,
+            _comma: input.parse()?
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct HtmlFor {
+    pub for_token: Token![for],
+    pub pat: Pat,
+    pub _in_token: Token![in],
+    pub expr: Expr,
+    /// Key used to reconcile the items of a reactive iterable (i.e. `key={item.id}`)
+    pub key: Option<Expr>,
+    pub body: Block
+}
+
+impl Parse for HtmlFor {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let for_token = input.parse()?;
+        let pat = input.parse()?;
+        let _in_token = input.parse()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+
+        let key = match input.peek(Ident) && input.peek2(Token![=]) {
+            true => {
+                let ident = input.parse::<Ident>()?;
+                if ident != "key" {
+                    return Err(syn::Error::new_spanned(ident, "expected `key` or the loop's body"))
+                }
+
+                input.parse::<Token![=]>()?;
+                let content; braced!(content in input);
+                Some(content.parse()?)
+            },
+            false => None
+        };
+
+        return Ok(Self { for_token, pat, _in_token, expr, key, body: input.parse()? })
+    }
+}
+
+#[derive(Debug, Parse)]
+pub struct HtmlSlot {
+    pub slot_token: Ident,
+    pub name: Ident,
+    pub body: Block
+}
+
+/// Interpolated text, split into it's static and interpolated pieces
+#[derive(Debug)]
+pub struct Format {
+    pub lit: LitStr,
+    pub pieces: Vec<FormatPiece>
+}
+
+#[derive(Debug)]
+pub enum FormatPiece {
+    /// Static text, with it's escaped braces already resolved
+    Str (String),
+    /// Interpolated value (i.e. `{count}` or `{price:.2}`), with the format string used to display it
+    Arg (Expr, String)
+}
+
+impl Parse for Format {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<LitStr>()?;
+        let value = lit.value();
+
+        let mut pieces = Vec::new();
+        let mut buf = String::new();
+        let mut chars = value.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => { chars.next(); buf.push('{') },
+                '}' if chars.peek() == Some(&'}') => { chars.next(); buf.push('}') },
+                '}' => return Err(syn::Error::new_spanned(&lit, "unmatched `}` in interpolated text. use `}}` to escape it")),
+                '{' => {
+                    let mut depth = 0usize;
+                    let mut arg = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') if depth == 0 => break,
+                            Some(c @ ('{' | '(' | '[')) => { depth += 1; arg.push(c) },
+                            Some(c @ ('}' | ')' | ']')) => { depth = depth.saturating_sub(1); arg.push(c) },
+                            Some(c) => arg.push(c),
+                            None => return Err(syn::Error::new_spanned(&lit, "unmatched `{` in interpolated text. use `{{` to escape it"))
+                        }
+                    }
+
+                    let (expr, spec) = split_format_spec(&arg);
+                    if expr.trim().is_empty() {
+                        return Err(syn::Error::new_spanned(&lit, "expected an expression inside `{}`"))
+                    }
+
+                    let expr = syn::parse_str::<Expr>(expr)
+                        .map_err(|e| syn::Error::new_spanned(&lit, format!("invalid interpolated expression `{}`: {e}", expr.trim())))?;
+
+                    if !buf.is_empty() {
+                        pieces.push(FormatPiece::Str(core::mem::take(&mut buf)));
+                    }
+
+                    let format = match spec {
+                        Some(spec) => format!("{{:{spec}}}"),
+                        None => String::from("{}")
+                    };
+                    pieces.push(FormatPiece::Arg(expr, format));
+                }
+                c => buf.push(c)
+            }
+        }
+
+        if !buf.is_empty() {
+            pieces.push(FormatPiece::Str(buf));
+        }
+
+        return Ok(Self { lit, pieces })
+    }
+}
+
+/// Splits an interpolated argument into it's expression and format spec (i.e. `price:.2` into `price` and `.2`).
+/// Paths (`a::b`) and nested delimiters are kept as part of the expression.
+fn split_format_spec (arg: &str) -> (&str, Option<&str>) {
+    let bytes = arg.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            b':' if bytes.get(i + 1) == Some(&b':') => i += 1,
+            b':' if depth == 0 => return (&arg[..i], Some(&arg[i + 1..])),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    return (arg, None)
+}
+
 pub struct Elements (pub Vec<Html>);
 
 impl Parse for Elements {
@@ -50,10 +334,20 @@ pub struct OpenElement {
     pub children: Vec<Html>,
     left: Token![<],
     shift: Token![/],
+    #[call(parse_tag)]
     pub path: Path,
     right: Token![>],
 }
 
+/// Parses an element's tag, which may also be a keyword (i.e. SVG's `<use>`)
+fn parse_tag (input: ParseStream) -> syn::Result<Path> {
+    let path_keyword = input.peek(Token![self]) || input.peek(Token![super]) || input.peek(Token![crate]) || input.peek(Token![Self]);
+    if !input.peek(Ident) && !path_keyword && input.peek(Ident::peek_any) {
+        return Ident::parse_any(input).map(Path::from)
+    }
+    return Path::parse_mod_style(input)
+}
+
 #[derive(Debug)]
 pub enum ElementEnd {
     Closed (ClosedElement),
@@ -73,18 +367,54 @@ impl Parse for ElementEnd {
 #[derive(Debug, Parse)]
 pub struct Element {
     pub left: Token![<],
-    #[call(Path::parse_mod_style)]
+    #[call(parse_tag)]
     pub path: Path,
     #[call(parse_attrs)]
-    pub attrs: Vec<ElementAttribute>,
+    pub attrs: Vec<ElementProp>,
     pub end: ElementEnd
 }
 
+#[derive(Debug)]
+pub enum ElementProp {
+    Attribute (ElementAttribute),
+    Spread (SpreadAttribute)
+}
+
+impl Parse for ElementProp {
+    #[inline]
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ahead = input.fork();
+        Attribute::parse_outer(&ahead)?;
+
+        if ahead.peek(syn::token::Brace) {
+            return SpreadAttribute::parse(input).map(Self::Spread)
+        }
+        return ElementAttribute::parse(input).map(Self::Attribute)
+    }
+}
+
+/// Collection of attributes spread into an element (i.e. `{..attrs}`)
+#[derive(Debug, Parse)]
+pub struct SpreadAttribute {
+    #[call(Attribute::parse_outer)]
+    pub attrs: Vec<Attribute>,
+    #[brace]
+    pub _brace_token: syn::token::Brace,
+    #[inside(_brace_token)]
+    pub dot2_token: Token![..],
+    #[inside(_brace_token)]
+    pub expr: Expr
+}
+
 #[derive(Debug, Parse)]
 pub struct ElementAttribute {
     #[call(Attribute::parse_outer)]
     pub attrs: Vec<Attribute>,
-    pub pat: Pat,
+    #[call(parse_directive)]
+    pub directive: Option<Directive>,
+    pub name: AttrName,
+    #[call(parse_modifiers)]
+    pub modifiers: Vec<Modifier>,
     pub eq_token: Token![=],
     #[brace]
     pub brace_token: syn::token::Brace,
@@ -92,11 +422,108 @@ pub struct ElementAttribute {
     pub expr: Expr
 }
 
+/// Prefix of a primitive element's attribute, either a directive that changes how it's value is applied (i.e. `prop:`)
+/// or the namespace of it's name (i.e. `xlink:`)
+#[derive(Debug, Parse)]
+pub struct Directive {
+    #[call(Ident::parse_any)]
+    pub ident: Ident,
+    pub _colon_token: Token![:]
+}
+
+/// Name of an element's attribute
+#[derive(Debug)]
+pub enum AttrName {
+    /// Hyphenated name (i.e. `href`, `data-id` or `aria-label`)
+    Ident (Punctuated<Ident, Token![-]>),
+    /// String literal name, for names that can't be written otherwise
+    Lit (LitStr)
+}
+
+impl AttrName {
+    /// Returns the name as a single identifier, if it is one
+    #[inline]
+    pub fn as_ident (&self) -> Option<&Ident> {
+        match self {
+            Self::Ident(x) if x.len() == 1 => x.first(),
+            _ => None
+        }
+    }
+
+    /// Returns the name as it's passed to the DOM
+    pub fn value (&self) -> String {
+        match self {
+            Self::Ident(x) => x.iter().map(|x| x.unraw().to_string()).collect::<Vec<_>>().join("-"),
+            Self::Lit(x) => x.value()
+        }
+    }
+
+    /// Returns the name as a string literal, prefixed by `prefix` (if any)
+    pub fn to_lit (&self, prefix: Option<&Ident>) -> LitStr {
+        let value = match prefix {
+            Some(prefix) => format!("{}:{}", prefix.unraw(), self.value()),
+            None => self.value()
+        };
+        return LitStr::new(&value, self.span())
+    }
+}
+
+impl Parse for AttrName {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return input.parse().map(Self::Lit)
+        }
+
+        let mut name = Punctuated::new();
+        name.push_value(Ident::parse_any(input)?);
+        while input.peek(Token![-]) {
+            name.push_punct(input.parse()?);
+            name.push_value(Ident::parse_any(input)?);
+        }
+
+        return Ok(Self::Ident(name))
+    }
+}
+
+impl ToTokens for AttrName {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Ident(x) => x.to_tokens(tokens),
+            Self::Lit(x) => x.to_tokens(tokens)
+        }
+    }
+}
+
+/// Modifier of an `on:` directive (i.e. `|prevent_default`)
+#[derive(Debug, Parse)]
+pub struct Modifier {
+    pub vert_token: Token![|],
+    pub ident: Ident
+}
+
+#[inline]
+fn parse_modifiers(input: ParseStream) -> syn::Result<Vec<Modifier>> {
+    let mut modifiers = Vec::new();
+    while input.peek(Token![|]) {
+        modifiers.push(Modifier::parse(input)?);
+    }
+    return Ok(modifiers)
+}
+
+#[inline]
+fn parse_directive(input: ParseStream) -> syn::Result<Option<Directive>> {
+    if input.peek(Ident::peek_any) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+        return Directive::parse(input).map(Some)
+    }
+    return Ok(None)
+}
+
 #[inline]
-fn parse_attrs(input: ParseStream) -> syn::Result<Vec<ElementAttribute>> {
+fn parse_attrs(input: ParseStream) -> syn::Result<Vec<ElementProp>> {
     let mut attrs = Vec::new();
     while !input.is_empty() && !input.peek(Token![/]) && !input.peek(Token![>]) {
-        attrs.push(ElementAttribute::parse(input)?);
+        attrs.push(ElementProp::parse(input)?);
     }
 
     return Ok(attrs)
@@ -3,23 +3,33 @@
 
 use std::ops::Deref;
 
-use html::{Element, Elements, ElementEnd, ElementAttribute, Html};
+use html::{Element, Elements, ElementEnd, ElementAttribute, ElementProp, SpreadAttribute, Html, Directive, Modifier, Format, FormatPiece, HtmlIf, HtmlElse, HtmlMatch, HtmlArm, HtmlFor, HtmlSlot};
 use proc_macro2::{TokenStream};
+use props::Prop;
+use tags::Namespace;
 use quote::{quote, ToTokens, quote_spanned};
-use syn::{parse_macro_input, ItemFn, Signature, spanned::Spanned, Pat, FnArg, PatType, punctuated::Punctuated, Token, Attribute, PatIdent, ext::IdentExt};
+use syn::{ext::IdentExt, parse_macro_input, ItemFn, Signature, spanned::Spanned, Pat, FnArg, PatType, punctuated::Punctuated, Token, Attribute, PatIdent, Ident, LitStr, Type, TypePath, TypeReference, TypeParen, TypeGroup, TypeTuple, PathArguments, GenericArgument, parse_quote, DeriveInput, Data, DataStruct, Fields, Field, Expr, parse::{Parse, ParseStream}};
 mod html;
+mod props;
+mod spec;
+mod tags;
 
 #[proc_macro]
 pub fn html (items: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut element: Vec<Html> = parse_macro_input!(items as Elements).0;
 
     if element.len() == 1 {
-        let (attrs, tokens) = html_html(element.swap_remove(0));
+        let (attrs, tokens) = html_html(element.swap_remove(0), Namespace::Html);
         return quote! { #(#attrs)* #tokens }.into()
     }
 
-    let element = element.into_iter().map(|html| {
-        let (attrs, tokens) = html_html(html);
+    return html_children(element, Namespace::Html).into()
+}
+
+/// Renders a list of children into a single [`DocumentFragment`](web_sys::DocumentFragment)
+fn html_children (children: Vec<Html>, ns: Namespace) -> TokenStream {
+    let children = children.into_iter().map(|html| {
+        let (attrs, tokens) = html_html(html, ns);
         return quote! {
             #(#attrs)*
             dynui::component::Node::append_child(
@@ -31,32 +41,124 @@ pub fn html (items: proc_macro::TokenStream) -> proc_macro::TokenStream {
     
     quote! {
         (|| {
-            let mut __fragment__ = dynui::web_sys::DocumentFragment::new()?;
-            #(#element)*
-            return unsafe {
-                dynui::Result::Ok(dynui::component::Node::new(__fragment__))
-            }
+            let __fragment__ = unsafe {
+                dynui::component::Node::new(dynui::web_sys::DocumentFragment::new()?)
+            };
+            #(#children)*
+            return dynui::Result::Ok(__fragment__)
         })()
-    }.into()
+    }
 }
 
-fn html_html (html: Html) -> (Vec<Attribute>, TokenStream) {
+fn html_html (html: Html, ns: Namespace) -> (Vec<Attribute>, TokenStream) {
     return match html {
         Html::Element(attrs, x) => {
-            let tokens = html_element(x);
+            let tokens = html_element(x, ns);
             return (attrs, tokens)
         },
         Html::Expr(attrs, x) => (attrs, quote! {
             dynui::component::Component::render(#x)
-        })
+        }),
+        Html::Text(attrs, x) => (attrs, quote! {
+            dynui::component::Component::render(#x)
+        }),
+        Html::Format(attrs, x) => {
+            let tokens = html_format(x);
+            return (attrs, quote! {
+                dynui::component::Component::render(#tokens)
+            })
+        },
+        Html::If(attrs, x) => (attrs, html_if(x, ns)),
+        Html::Match(attrs, x) => (attrs, html_match(x, ns)),
+        Html::For(attrs, x) => (attrs, html_for(x, ns)),
+        Html::Slot(attrs, HtmlSlot { slot_token, .. }) => (attrs, syn::Error::new_spanned(slot_token, "slots are only allowed as children of components").to_compile_error())
     }
 }
 
-fn html_element (Element { path, attrs, end, .. }: Element) -> TokenStream {
+/// Branches are moved into closures, so that conditions that are cells can render the selected branch again whenever they flip, like [`Show`](dynui::lib::control::Show).
+/// Plain booleans only render the selected branch.
+fn html_if (HtmlIf { if_token, cond, then, otherwise }: HtmlIf, ns: Namespace) -> TokenStream {
+    let then = html_children(then.children, ns);
+    let otherwise = match otherwise {
+        Some((_, HtmlElse::If(x))) => html_if(*x, ns),
+        Some((_, HtmlElse::Block(x))) => html_children(x.children, ns),
+        None => quote! { () }
+    };
+
+    quote_spanned! { if_token.span() => {
+        #[allow(unused_imports)]
+        use dynui::lib::control::{ConditionCell as _, ConditionValue as _};
+        (&dynui::lib::control::Condition(&(#cond))).choose(
+            move || #then,
+            move || #otherwise
+        )
+    }}
+}
+
+/// Arms are moved into a closure, so that they can be rendered again whenever the scrutinee is a cell that's updated
+fn html_match (HtmlMatch { match_token, expr, arms }: HtmlMatch, ns: Namespace) -> TokenStream {
+    let arms = arms.into_iter().map(|HtmlArm { pat, guard, fat_arrow_token, body, .. }| {
+        let guard = guard.map(|(if_token, expr)| quote! { #if_token #expr });
+        let body = html_children(body.children, ns);
+        quote! { #pat #guard #fat_arrow_token dynui::component::Component::render(#body), }
+    });
+
+    quote_spanned! { match_token.span() => {
+        #[allow(unused_imports)]
+        use dynui::lib::control::{ScrutineeCell as _, ScrutineeValue as _};
+        (&dynui::lib::control::Scrutinee(&(#expr))).switch(move |r#__value__| match r#__value__ {
+            #(#arms)*
+        })
+    }}
+}
+
+/// The loop's body (and key) are moved into a closure, so that they can be rendered again whenever the iterable is a cell that's updated
+fn html_for (HtmlFor { for_token, pat, expr, key, body, .. }: HtmlFor, ns: Namespace) -> TokenStream {
+    let body = html_children(body.children, ns);
+    let call = match key {
+        Some(key) => quote! {
+            each_keyed(move |#pat| #key, move |#pat| #body)
+        },
+        None => quote! {
+            each(move |#pat| #body)
+        }
+    };
+
+    quote_spanned! { for_token.span() => {
+        #[allow(unused_imports)]
+        use dynui::lib::list::{EachCell as _, EachValue as _};
+        (&dynui::lib::list::Each::new(#expr)).#call
+    }}
+}
+
+fn html_format (Format { lit, pieces }: Format) -> TokenStream {
+    let pieces = pieces.into_iter().map(|piece| match piece {
+        FormatPiece::Str(x) => quote_spanned! { lit.span() =>
+            r#__text__.push_str(#x);
+        },
+        FormatPiece::Arg(expr, format) => {
+            let format = LitStr::new(&format, lit.span());
+            quote_spanned! { lit.span() =>
+                (&dynui::text::Interpolated(&(#expr))).interpolate(
+                    &r#__text__,
+                    |x| ::std::format!(#format, x)
+                );
+            }
+        }
+    });
+
+    quote! {(|| {
+        #[allow(unused_imports)]
+        use dynui::text::{InterpolateCell as _, InterpolateValue as _};
+        let r#__text__ = dynui::text::Interpolation::new()?;
+        #(#pieces)*
+        return dynui::Result::Ok(r#__text__.finish())
+    })()}
+}
+
+fn html_element (Element { path, attrs, end, .. }: Element, parent: Namespace) -> TokenStream {
     let mut children = Vec::new();
     if let ElementEnd::Open(close) = end {
-        children.reserve(close.children.len());
-
         if path != close.path {
             return syn::Error::new(
                 path.span().join(close.path.span()).unwrap(), 
@@ -66,68 +168,272 @@ fn html_element (Element { path, attrs, end, .. }: Element) -> TokenStream {
                     close.path.to_token_stream().to_string()
                 )).into_compile_error();
         }
-
-        for child in close.children {
-            let (attrs, value) = html_html(child);
-            children.push(quote! {
-                #(#attrs)*
-                dynui::component::Node::append_child(
-                    &r#__element__,
-                    dynui::component::Component::render(#value)?
-                )?;
-            });
-        }
+        children = close.children;
     }
 
     let tokens = match path.get_ident() {
         Some(x) if x.to_string().starts_with(char::is_lowercase) => {
-            let props = attrs.into_iter()
-                .map(|ElementAttribute { attrs, pat, expr, .. }| {
-                    let ident = match pat {
-                        Pat::Ident(pat) => IdentExt::unraw(&pat.ident),
-                        other => return syn::Error::new_spanned(other, "only identity patterns are allowed as primitive props").to_compile_error()
-                    };
-
-                    quote! {
-                        #(#attrs)*
-                        dynui::component::Element::set_attribute(
-                            &r#__element__,
-                            stringify!(#ident),
-                            #expr
-                        )?;
-                    }
-                });
+            let tag = x.to_string();
+            let ns = parent.element(&tag);
+            if let Err(e) = tags::validate_tag(ns, x) {
+                return e.to_compile_error()
+            }
+
+            if !children.is_empty() {
+                if let Err(e) = tags::validate_children(ns, x) {
+                    return e.to_compile_error()
+                }
+            }
+
+            // Properties and bindings are applied once the children exist (i.e. a `<select>`'s value must match one of it's options)
+            let (late, attrs) = attrs.into_iter().partition::<Vec<_>, _>(|prop| matches!(
+                prop,
+                ElementProp::Attribute(ElementAttribute { directive: Some(Directive { ident, .. }), .. }) if ident == "prop" || ident == "bind"
+            ));
+
+            let late = late.into_iter().map(|prop| match prop {
+                ElementProp::Attribute(attr) => primitive_attribute(attr),
+                ElementProp::Spread(_) => unreachable!()
+            });
+
+            let props = attrs.into_iter().map(|prop| match prop {
+                ElementProp::Attribute(attr) if attr.directive.is_none() => match tags::validate_attribute(ns, &tag, &attr.name) {
+                    Ok(_) => primitive_attribute(attr),
+                    Err(e) => e.to_compile_error()
+                },
+                ElementProp::Attribute(attr) => primitive_attribute(attr),
+                ElementProp::Spread(SpreadAttribute { attrs, expr, .. }) => quote! {
+                    #(#attrs)*
+                    dynui::component::Element::set_attributes(&r#__element__, #expr)?;
+                }
+            });
+
+            let children_ns = ns.children(&tag);
+            let children = children.into_iter().map(|child| {
+                let (attrs, value) = html_html(child, children_ns);
+                quote! {
+                    #(#attrs)*
+                    dynui::component::Element::append_child(
+                        &r#__element__,
+                        dynui::component::Component::render(#value)?
+                    )?;
+                }
+            });
+
+            let interface = Ident::new(ns.interface(&tag), x.span());
+            let create = match ns.uri() {
+                Some(uri) => quote! { dynui::create_element_ns(::core::option::Option::Some(#uri), stringify!(#path)) },
+                None => quote! { dynui::create_element(stringify!(#path)) }
+            };
 
             quote! {
-                let mut r#__element__ = dynui::create_element(stringify!(#path))?;
+                let mut r#__element__ = #create?
+                    .unchecked_into::<dynui::web_sys::#interface>();
                 #(#props)*
+                #(#children)*
+                #(#late)*
             }
         },
 
         _ => {
-            let props = attrs.into_iter()
-                .map(|ElementAttribute { attrs, pat, expr, .. }| quote! { #(#attrs)* #pat: #expr });
+            let attrs = match attrs.into_iter()
+                .map(|prop| match prop {
+                    ElementProp::Attribute(attr) => Ok(attr),
+                    ElementProp::Spread(SpreadAttribute { dot2_token, .. }) => Err(syn::Error::new_spanned(dot2_token, "spread attributes are only allowed on primitive elements"))
+                })
+                .try_collect::<Vec<_>>() {
+                    Ok(x) => x,
+                    Err(e) => return e.to_compile_error()
+                };
+
+            if let Some(Directive { ident, .. }) = attrs.iter().find_map(|x| x.directive.as_ref()) {
+                return syn::Error::new_spanned(ident, "directives are only allowed on primitive elements").to_compile_error()
+            }
+
+            if let Some(Modifier { vert_token, .. }) = attrs.iter().find_map(|x| x.modifiers.first()) {
+                return syn::Error::new_spanned(vert_token, "modifiers are only allowed on `on:` directives").to_compile_error()
+            }
+
+            if let Some(ElementAttribute { name, .. }) = attrs.iter().find(|x| x.name.as_ident().is_some_and(|x| x == "ref")) {
+                return syn::Error::new_spanned(name, "refs are only allowed on primitive elements").to_compile_error()
+            }
+
+            let mut props = match attrs.into_iter()
+                .map(|ElementAttribute { attrs, name, expr, .. }| match name.as_ident() {
+                    Some(ident) => Ok(quote! { #(#attrs)* let r#__element__ = r#__element__.#ident(#expr); }),
+                    None => Err(syn::Error::new_spanned(name, "component props must be identifiers"))
+                })
+                .try_collect::<Vec<_>>() {
+                    Ok(x) => x,
+                    Err(e) => return e.to_compile_error()
+                };
+
+            // Named slots become props of their own, and everything else is passed as the `children` prop
+            let mut rest = Vec::with_capacity(children.len());
+            for child in children {
+                match child {
+                    Html::Slot(attrs, HtmlSlot { name, body, .. }) => {
+                        let body = html_lazy(body.children, parent);
+                        props.push(quote! { #(#attrs)* let r#__element__ = r#__element__.#name(#body); })
+                    },
+                    other => rest.push(other)
+                }
+            }
+
+            if !rest.is_empty() {
+                let children = html_lazy(rest, parent);
+                props.push(quote! { let r#__element__ = r#__element__.children(#children); })
+            }
 
             quote! {
-                let mut r#__element__ = #path { #(#props),* };
+                let r#__element__ = {
+                    #[allow(unused_imports)]
+                    use dynui::component::PropsFallback as _;
+                    #path::builder()
+                };
+                #(#props)*
+                let mut r#__element__ = r#__element__.build();
             }
         }
     };
 
     quote! {(|| {
         #tokens
-        #(#children)*
         return dynui::Result::Ok(r#__element__)
     })()}
 }
 
+/// Wraps `children` into a closure, so that they're rendered whenever (and as many times as) the component wants.
+/// The closure is converted into [`Children`](dynui::component::Children) or [`ChildrenOnce`](dynui::component::ChildrenOnce) by the prop's setter
+fn html_lazy (children: Vec<Html>, ns: Namespace) -> TokenStream {
+    let children = html_children(children, ns);
+    quote! {
+        dynui::component::Lazy(move || #children)
+    }
+}
+
+fn primitive_attribute (ElementAttribute { attrs, directive, name, modifiers, expr, .. }: ElementAttribute) -> TokenStream {
+    if let Some(Modifier { vert_token, .. }) = modifiers.first() {
+        if !directive.as_ref().is_some_and(|x| x.ident == "on") {
+            return syn::Error::new_spanned(vert_token, "modifiers are only allowed on `on:` directives").to_compile_error()
+        }
+    }
+
+    if directive.is_none() && name.as_ident().is_some_and(|x| x == "ref") {
+        return quote! {
+            #(#attrs)*
+            dynui::component::NodeRef::set(&#expr, &r#__element__);
+        }
+    }
+
+    let value = name.to_lit(None);
+    let method = match directive {
+        None => quote! { set_attribute },
+        Some(Directive { ident: directive, .. }) if directive == "prop" => quote! { set_property },
+        Some(Directive { ident: directive, .. }) if directive == "class" => quote! { set_class },
+        Some(Directive { ident: directive, .. }) if directive == "style" => quote! { set_style },
+
+        Some(Directive { ident: directive, .. }) if directive == "on" => {
+            let options = match listener_options(&modifiers) {
+                Ok(x) => x,
+                Err(e) => return e.to_compile_error()
+            };
+
+            return quote! {
+                #(#attrs)*
+                dynui::component::Element::add_listener_with_options(
+                    &r#__element__,
+                    #value,
+                    #options,
+                    #expr
+                )?;
+            }
+        },
+
+        Some(Directive { ident: directive, .. }) if directive == "bind" => {
+            let method = match value.value().as_str() {
+                "value" => quote! { bind_value },
+                "checked" => quote! { bind_checked },
+                "group" => quote! { bind_group },
+                other => return syn::Error::new_spanned(
+                    &name,
+                    format!("unknown binding `{other}`. expected one of `value`, `checked` or `group`")
+                ).to_compile_error()
+            };
+
+            return quote! {
+                #(#attrs)*
+                dynui::component::Element::#method(&r#__element__, #expr)?;
+            }
+        },
+
+        // Namespaced attribute (i.e. `xlink:href`)
+        Some(Directive { ident: prefix, .. }) => {
+            let Some(uri) = tags::attribute_namespace(&prefix.unraw().to_string()) else {
+                return syn::Error::new_spanned(&prefix, format!("unknown directive `{prefix}`")).to_compile_error()
+            };
+
+            let value = name.to_lit(Some(&prefix));
+            return quote! {
+                #(#attrs)*
+                dynui::component::Element::set_attribute_ns(
+                    &r#__element__,
+                    ::core::option::Option::Some(#uri),
+                    #value,
+                    #expr
+                )?;
+            }
+        }
+    };
+
+    quote! {
+        #(#attrs)*
+        dynui::component::Element::#method(
+            &r#__element__,
+            #value,
+            #expr
+        )?;
+    }
+}
+
+fn listener_options (modifiers: &[Modifier]) -> syn::Result<TokenStream> {
+    const MODIFIERS: &[&str] = &["prevent_default", "stop_propagation", "once", "passive", "capture"];
+
+    let mut fields = Vec::<&Ident>::with_capacity(modifiers.len());
+    for Modifier { ident, .. } in modifiers {
+        if !MODIFIERS.iter().any(|x| ident == x) {
+            return Err(syn::Error::new_spanned(ident, format!("unknown modifier `{ident}`. expected one of {}", MODIFIERS.iter().map(|x| format!("`{x}`")).collect::<Vec<_>>().join(", "))))
+        }
+        if fields.contains(&ident) {
+            return Err(syn::Error::new_spanned(ident, format!("duplicate modifier `{ident}`")))
+        }
+        fields.push(ident);
+    }
+
+    if let (Some(passive), true) = (fields.iter().find(|x| **x == "passive"), fields.iter().any(|x| *x == "prevent_default")) {
+        return Err(syn::Error::new_spanned(passive, "passive listeners can't prevent the default action"))
+    }
+
+    return Ok(quote! {
+        dynui::component::ListenerOptions {
+            #(#fields: true,)*
+            ..::core::default::Default::default()
+        }
+    })
+}
+
 #[proc_macro_attribute]
-pub fn component (_attrs: proc_macro::TokenStream, items: proc_macro::TokenStream) -> proc_macro::TokenStream {    
+pub fn component (args: proc_macro::TokenStream, items: proc_macro::TokenStream) -> proc_macro::TokenStream {    
+    let ComponentArgs { fallback } = parse_macro_input!(args as ComponentArgs);
     let ItemFn { attrs, vis, sig, block } = parse_macro_input!(items as ItemFn);
     let Signature { constness, asyncness, unsafety, ident, generics, inputs, output, .. } = sig;
+
+    if let (Some((fallback_token, _)), None) = (&fallback, &asyncness) {
+        return syn::Error::new_spanned(fallback_token, "fallbacks are only allowed on async components").to_compile_error().into()
+    }
     let (impl_generics, ty_generics, where_generics) = generics.split_for_impl();
 
-    let inputs = match inputs.into_iter().map(|arg| match arg {
+    let mut inputs = match inputs.into_iter().map(|arg| match arg {
         FnArg::Typed(pat) => Ok(pat),
         FnArg::Receiver(recv) => Err(syn::Error::new_spanned(recv, "only typed arguments are allowed as component props"))
     }).try_collect::<Punctuated<_, Token![,]>>() {
@@ -135,6 +441,17 @@ pub fn component (_attrs: proc_macro::TokenStream, items: proc_macro::TokenStrea
         Err(e) => return e.to_compile_error().into()
     };
 
+    let builder_props = match inputs.iter_mut()
+        .map(|PatType { attrs, pat, ty, .. }| match &**pat {
+            Pat::Ident(PatIdent { ident, .. }) => Prop::new(ident.clone(), Type::clone(ty), attrs),
+            other => Err(syn::Error::new_spanned(other, "component props must be identifiers"))
+        })
+        .try_collect::<Vec<_>>() {
+            Ok(x) => x,
+            Err(e) => return e.to_compile_error().into()
+        };
+    let builder = props::builder(&vis, &ident, &generics, &builder_props);
+
     let props = inputs.iter()
         .map(|pat_ty @ PatType { attrs, pat, colon_token, ty }| {
             let ident = match pat.deref() {
@@ -161,9 +478,47 @@ pub fn component (_attrs: proc_macro::TokenStream, items: proc_macro::TokenStrea
         })
         .collect::<Vec<_>>();
 
+    // Untyped elements in the output accept elements of any interface (i.e. `Result<Element>` accepts `html! { <div /> }`)
     let output = match output {
-        out @ syn::ReturnType::Default => quote_spanned! { out.span() => dynui::component::Component },
-        syn::ReturnType::Type(_, ty) => quote_spanned! { ty.span() => #ty as dynui::component::Component },
+        syn::ReturnType::Type(arrow, mut ty) => {
+            infer_elements(&mut ty);
+            syn::ReturnType::Type(arrow, ty)
+        },
+        other => other
+    };
+
+    // Async components render their fallback right away, and swap it with their output once it's ready
+    let body = match asyncness {
+        Some(_) => {
+            let output = match output {
+                out @ syn::ReturnType::Default => quote_spanned! { out.span() => () },
+                syn::ReturnType::Type(_, ty) => quote_spanned! { ty.span() => #ty },
+            };
+
+            let fallback = match fallback {
+                Some((_, expr)) => quote! { #expr },
+                None => quote! { () }
+            };
+
+            quote! {
+                let r#__future__ = async move {
+                    let r#__output__: #output = async move #block.await;
+                    return r#__output__
+                };
+                return dynui::component::Component::render(dynui::lib::r#async::Future::new(r#__future__, #fallback))
+            }
+        },
+
+        None => {
+            let output = match output {
+                out @ syn::ReturnType::Default => quote_spanned! { out.span() => dynui::component::Component },
+                syn::ReturnType::Type(_, ty) => quote_spanned! { ty.span() => #ty as dynui::component::Component },
+            };
+
+            quote! {
+                return <#output>::render((move || #block)())
+            }
+        }
     };
 
     quote! {
@@ -182,11 +537,121 @@ pub fn component (_attrs: proc_macro::TokenStream, items: proc_macro::TokenStrea
             }
         }
 
+        #builder
+
         impl #impl_generics #constness dynui::component::Component for #ident #ty_generics #where_generics {
             fn render (self) -> dynui::Result<dynui::component::Node> {
                 let Self { #(#render_inputs),* } = self;
-                return <#output>::render((move || #block)())
+                #body
             }
         }
     }.into()
-}
\ No newline at end of file
+}
+
+/// Replaces every untyped [`Element`](dynui::component::Element) inside `ty` with `Element<_>`,
+/// so that it's interface is inferred from the component's body
+fn infer_elements (ty: &mut Type) {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            let untyped = match path.segments.iter().rev().collect::<Vec<_>>().as_slice() {
+                [last, rest @ ..] if last.ident == "Element" && last.arguments.is_empty() => rest.first().map_or(true, |x| x.ident == "component"),
+                _ => false
+            };
+
+            for segment in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in args.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            infer_elements(ty)
+                        }
+                    }
+                }
+            }
+
+            if let (true, Some(last)) = (untyped, path.segments.last_mut()) {
+                last.arguments = PathArguments::AngleBracketed(parse_quote! { <_> })
+            }
+        },
+        Type::Reference(TypeReference { elem, .. }) | Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) => infer_elements(elem),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter_mut().for_each(infer_elements),
+        _ => {}
+    }
+}
+
+/// Arguments of the `#[component]` attribute (i.e. `#[component(fallback = "Loading")]`)
+struct ComponentArgs {
+    /// Rendered by async components while their body is pending
+    fallback: Option<(Ident, Expr)>
+}
+
+impl Parse for ComponentArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self { fallback: None })
+        }
+
+        let ident = input.parse::<Ident>()?;
+        if ident != "fallback" {
+            return Err(syn::Error::new_spanned(ident, "unknown component option. expected `fallback`"))
+        }
+
+        input.parse::<Token![=]>()?;
+        let expr = input.parse()?;
+        return Ok(Self { fallback: Some((ident, expr)) })
+    }
+}
+
+/// Generates the props builder used by `html!` for a component struct with named fields.
+/// Fields accept the same `#[prop(...)]` options as the parameters of a `#[component]` function.
+#[proc_macro_derive(Props, attributes(prop))]
+pub fn props (items: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let DeriveInput { vis, ident, generics, data, .. } = parse_macro_input!(items as DeriveInput);
+    return match struct_props(&ident, data) {
+        Ok(props) => props::builder(&vis, &ident, &generics, &props).into(),
+        Err(e) => e.to_compile_error().into()
+    }
+}
+
+/// Turns a struct into a component, rendered by reference with it's `view` method (i.e. `fn view (&self) -> impl Component + '_`).
+/// Like [`Props`], it generates the props builder used by `html!`, and fields marked with `#[prop(skip)]`
+/// can hold the component's internal state, since they're initialized with their default value instead of being set.
+#[proc_macro_derive(Component, attributes(prop))]
+pub fn derive_component (items: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let DeriveInput { vis, ident, generics, data, .. } = parse_macro_input!(items as DeriveInput);
+    let props = match struct_props(&ident, data) {
+        Ok(x) => x,
+        Err(e) => return e.to_compile_error().into()
+    };
+
+    let builder = props::builder(&vis, &ident, &generics, &props);
+    let (impl_generics, ty_generics, where_generics) = generics.split_for_impl();
+
+    quote! {
+        #builder
+
+        impl #impl_generics dynui::component::RefComponent for #ident #ty_generics #where_generics {
+            #[inline]
+            fn render (&self) -> dynui::Result<dynui::component::Node> {
+                return dynui::component::Component::render(self.view())
+            }
+        }
+
+        impl #impl_generics dynui::component::Component for #ident #ty_generics #where_generics {
+            #[inline]
+            fn render (self) -> dynui::Result<dynui::component::Node> {
+                return dynui::component::RefComponent::render(&self)
+            }
+        }
+    }.into()
+}
+
+fn struct_props (ident: &Ident, data: Data) -> syn::Result<Vec<Prop>> {
+    let fields = match data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => fields.named,
+        _ => return Err(syn::Error::new_spanned(ident, "components can only be derived for structs with named fields"))
+    };
+
+    return fields.into_iter()
+        .map(|Field { mut attrs, ident, ty, .. }| Prop::new(ident.unwrap(), ty, &mut attrs))
+        .try_collect::<Vec<_>>()
+}
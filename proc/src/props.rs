@@ -0,0 +1,339 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, format_ident, ToTokens};
+use syn::{Attribute, Expr, Generics, GenericParam, Ident, Token, Type, TypePath, TypeParam, Visibility, PathArguments, GenericArgument, WherePredicate, parse_quote};
+use syn::{parse::{Parse, ParseStream}, punctuated::Punctuated, ext::IdentExt};
+
+/// How a prop behaves when it's omitted
+pub enum PropKind {
+    /// The prop must always be set
+    Required,
+    /// The prop defaults to [`Default::default`], or to the specified expression.
+    /// Props typed by a type parameter of their own (i.e. `#[prop(default)] fallback: F`) default to `()` instead,
+    /// and their setter changes the parameter's type
+    Default (Option<Expr>),
+    /// The prop is an `Option<T>` whose setter takes a `T`, and it defaults to `None`
+    Optional (Type)
+}
+
+/// Prop of a component, as seen by it's builder
+pub struct Prop {
+    pub ident: Ident,
+    pub ty: Type,
+    pub kind: PropKind,
+    pub into: bool,
+    /// Whether the prop is internal state, initialized with it's default and without a setter
+    pub skip: bool
+}
+
+impl Prop {
+    /// Creates a new prop from it's `#[prop(...)]` attributes, removing them from `attrs`
+    pub fn new (ident: Ident, ty: Type, attrs: &mut Vec<Attribute>) -> syn::Result<Self> {
+        let mut kind = PropKind::Required;
+        let mut into = false;
+        let mut skip = false;
+
+        let (options, rest) = core::mem::take(attrs).into_iter().partition::<Vec<_>, _>(|x| x.path.is_ident("prop"));
+        *attrs = rest;
+
+        for attr in options {
+            for arg in attr.parse_args_with(Punctuated::<PropArg, Token![,]>::parse_terminated)? {
+                match (arg, &kind) {
+                    (PropArg::Into(_), _) if !into => into = true,
+                    (PropArg::Skip(_), _) if !skip => skip = true,
+                    (PropArg::Default(_, expr), PropKind::Required) => kind = PropKind::Default(expr),
+                    (PropArg::Optional(ident), PropKind::Required) => match option_inner(&ty) {
+                        Some(inner) => kind = PropKind::Optional(inner.clone()),
+                        None => return Err(syn::Error::new_spanned(ident, "optional props must be of type `Option<T>`"))
+                    },
+                    (PropArg::Into(ident) | PropArg::Skip(ident) | PropArg::Default(ident, _) | PropArg::Optional(ident), _) => {
+                        return Err(syn::Error::new_spanned(&ident, format!("conflicting prop option `{ident}`")))
+                    }
+                }
+            }
+        }
+
+        if skip && matches!(kind, PropKind::Required) {
+            kind = PropKind::Default(None)
+        }
+
+        // Markup nested inside the component's tag is converted into it's children by the setter
+        let target = match kind {
+            PropKind::Optional(ref inner) => inner,
+            _ => &ty
+        };
+        into |= is_children(target);
+
+        return Ok(Self { ident, ty, kind, into, skip })
+    }
+
+    #[inline]
+    fn is_required (&self) -> bool {
+        matches!(self.kind, PropKind::Required)
+    }
+}
+
+enum PropArg {
+    Default (Ident, Option<Expr>),
+    Optional (Ident),
+    Into (Ident),
+    Skip (Ident)
+}
+
+impl Parse for PropArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        return match ident.to_string().as_str() {
+            "default" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+                Ok(Self::Default(ident, Some(input.parse()?)))
+            },
+            "default" => Ok(Self::Default(ident, None)),
+            "optional" => Ok(Self::Optional(ident)),
+            "into" => Ok(Self::Into(ident)),
+            "skip" => Ok(Self::Skip(ident)),
+            other => Err(syn::Error::new_spanned(&ident, format!("unknown prop option `{other}`. expected one of `default`, `optional`, `into` or `skip`")))
+        }
+    }
+}
+
+/// Returns `T`, if `ty` is `Option<T>`
+fn option_inner (ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" { return None }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    return match args.args.first() {
+        Some(GenericArgument::Type(ty)) if args.args.len() == 1 => Some(ty),
+        _ => None
+    }
+}
+
+/// Returns `true` if `ty` is [`Children`](dynui::component::Children) or [`ChildrenOnce`](dynui::component::ChildrenOnce)
+fn is_children (ty: &Type) -> bool {
+    let Type::Path(TypePath { qself: None, path }) = ty else { return false };
+    return path.segments.last().is_some_and(|x| (x.ident == "Children" || x.ident == "ChildrenOnce") && x.arguments.is_empty())
+}
+
+/// Returns the type parameter of `generics` that types `prop`, if the prop is defaulted and the parameter isn't used anywhere else,
+/// so that the prop may start out as `()` and get it's type once it's set
+fn generic_default<'a> (prop: &Prop, generics: &'a Generics, props: &[Prop]) -> Option<&'a TypeParam> {
+    let (PropKind::Default(None), false) = (&prop.kind, prop.skip) else { return None };
+    let Type::Path(TypePath { qself: None, path }) = &prop.ty else { return None };
+    let ident = path.get_ident()?;
+    let param = generics.type_params().find(|x| x.ident == *ident)?;
+
+    let mut others = props.iter().filter(|x| x.ident != prop.ident).map(|x| x.ty.to_token_stream())
+        .chain(generics.type_params().filter(|x| x.ident != *ident).map(|x| x.bounds.to_token_stream()))
+        .chain(generics.where_clause.iter().flat_map(|x| &x.predicates).filter(|x| !bounds_param(x, ident)).map(|x| x.to_token_stream()));
+
+    return match others.any(|x| mentions(x, ident)) {
+        true => None,
+        false => Some(param)
+    }
+}
+
+/// Returns `true` if `predicate` bounds the type parameter `ident` (i.e. `F: Component`)
+fn bounds_param (predicate: &WherePredicate, ident: &Ident) -> bool {
+    match predicate {
+        WherePredicate::Type(x) => matches!(&x.bounded_ty, Type::Path(TypePath { qself: None, path }) if path.is_ident(ident)),
+        _ => false
+    }
+}
+
+/// Returns `true` if `tokens` contain `ident`
+fn mentions (tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|x| match x {
+        TokenTree::Ident(x) => x == *ident,
+        TokenTree::Group(x) => mentions(x.stream(), ident),
+        _ => false
+    })
+}
+
+/// Generates the props builder of the component `ident`, returned by `#ident::builder()` (and by it's [`Props`](dynui::component::Props) impl, which `html!` uses).
+/// Every required prop is tracked by a type parameter of the builder, which starts out as a marker type named after the prop
+/// and becomes [`Set`](dynui::component::Set) once it's setter is called, so `build` can only be called once all of them are set.
+pub fn builder (vis: &Visibility, ident: &Ident, generics: &Generics, props: &[Prop]) -> TokenStream {
+    let builder = format_ident!("{}Builder", ident);
+    let markers = format_ident!("__{}_props", ident);
+    let builder_doc = format!("Builder for the props of [`{ident}`]");
+
+    let args = generics.params.iter().map(args_of).collect::<Vec<_>>();
+
+    let required = props.iter().filter(|x| x.is_required()).map(|x| &x.ident).collect::<Vec<_>>();
+    let states = (0..required.len()).map(|i| format_ident!("__S{}", i)).collect::<Vec<_>>();
+
+    let mut setter_generics = generics.clone();
+    for state in states.iter() {
+        setter_generics.params.push(parse_quote! { #state });
+    }
+
+    let (setter_impl_generics, _, setter_where) = setter_generics.split_for_impl();
+
+    // Props typed by a type parameter of their own are stored as is, since they always have a value (`()` until they're set)
+    let generic = props.iter().map(|x| generic_default(x, generics, props)).collect::<Vec<_>>();
+
+    let idents = props.iter().map(|x| &x.ident).collect::<Vec<_>>();
+    let fields = props.iter().zip(&generic).map(|(Prop { ident, ty, .. }, generic)| match generic {
+        Some(_) => quote! { #ident: #ty },
+        None => quote! { #ident: ::core::option::Option<#ty> }
+    });
+
+    let initial = props.iter().zip(&generic).map(|(Prop { ident, .. }, generic)| match generic {
+        Some(_) => quote! { #ident: () },
+        None => quote! { #ident: ::core::option::Option::None }
+    });
+
+    let builder_args = generics.params.iter().map(|param| match param {
+        GenericParam::Type(x) if generic.iter().flatten().any(|y| y.ident == x.ident) => quote! { () },
+        other => args_of(other)
+    }).collect::<Vec<_>>();
+
+    // `builder` lives in an impl where the parameters of those props are `()`
+    let mut builder_generics = generics.clone();
+    builder_generics.params = builder_generics.params.into_iter()
+        .filter(|x| !matches!(x, GenericParam::Type(x) if generic.iter().flatten().any(|y| y.ident == x.ident)))
+        .collect();
+
+    if let Some(clause) = builder_generics.where_clause.as_mut() {
+        clause.predicates = core::mem::take(&mut clause.predicates).into_iter()
+            .filter(|x| !generic.iter().flatten().any(|y| bounds_param(x, &y.ident)))
+            .collect();
+    }
+    let (builder_impl_generics, _, builder_where) = builder_generics.split_for_impl();
+
+    let mut state_idx = 0;
+    let setters = props.iter().zip(&generic).filter(|(x, _)| !x.skip).map(|(prop @ Prop { ident, ty, kind, into, .. }, generic)| {
+        let others = idents.iter().filter(|x| **x != ident);
+        let doc = format!("Sets the `{}` prop", ident.unraw());
+
+        // The setter replaces the prop's type parameter with the type of it's value
+        if let Some(TypeParam { ident: param, bounds, .. }) = generic {
+            let output = generics.params.iter().map(|x| match x {
+                GenericParam::Type(x) if x.ident == *param => quote! { __T },
+                other => args_of(other)
+            });
+
+            let predicates = generics.where_clause.iter()
+                .flat_map(|x| &x.predicates)
+                .filter(|x| bounds_param(x, param))
+                .filter_map(|x| match x {
+                    WherePredicate::Type(x) => Some(&x.bounds),
+                    _ => None
+                });
+
+            return quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #ident<__T: #bounds> (self, #ident: __T) -> #builder<#(#output,)* #(#states),*> where #(__T: #predicates),* {
+                    return #builder {
+                        #ident,
+                        #(#others: self.#others,)*
+                        __state: ::core::marker::PhantomData
+                    }
+                }
+            }
+        }
+
+        let ty = match kind {
+            PropKind::Optional(inner) => inner,
+            _ => ty
+        };
+
+        let (arg_ty, mut value) = match into {
+            true => (quote! { impl ::core::convert::Into<#ty> }, quote! { ::core::convert::Into::into(#ident) }),
+            false => (quote! { #ty }, quote! { #ident })
+        };
+
+        if let PropKind::Optional(_) = kind {
+            value = quote! { ::core::option::Option::Some(#value) }
+        }
+
+        let mut output = states.iter().map(|x| quote! { #x }).collect::<Vec<_>>();
+        if prop.is_required() {
+            output[state_idx] = quote! { dynui::component::Set };
+            state_idx += 1;
+        }
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn #ident (self, #ident: #arg_ty) -> #builder<#(#args,)* #(#output),*> {
+                return #builder {
+                    #ident: ::core::option::Option::Some(#value),
+                    #(#others: self.#others,)*
+                    __state: ::core::marker::PhantomData
+                }
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    let values = props.iter().zip(&generic).map(|(Prop { ident, kind, .. }, generic)| match kind {
+        _ if generic.is_some() => quote! { #ident: self.#ident },
+        PropKind::Required => quote! {
+            #ident: match self.#ident {
+                ::core::option::Option::Some(x) => x,
+                ::core::option::Option::None => unreachable!()
+            }
+        },
+        PropKind::Default(None) => quote! { #ident: self.#ident.unwrap_or_default() },
+        PropKind::Default(Some(expr)) => quote! { #ident: self.#ident.unwrap_or_else(|| #expr) },
+        PropKind::Optional(_) => quote! { #ident: self.#ident.unwrap_or(::core::option::Option::None) }
+    });
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case, non_camel_case_types)]
+        #vis mod #markers {
+            #(pub struct #required;)*
+        }
+
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder #setter_impl_generics #setter_where {
+            #(#fields,)*
+            __state: ::core::marker::PhantomData<(#(#states,)*)>
+        }
+
+        impl #builder_impl_generics #ident<#(#builder_args),*> #builder_where {
+            /// Returns a builder for the component's props
+            #[inline]
+            #vis fn builder () -> #builder<#(#builder_args,)* #(#markers::#required),*> {
+                return #builder {
+                    #(#initial,)*
+                    __state: ::core::marker::PhantomData
+                }
+            }
+        }
+
+        impl #builder_impl_generics dynui::component::Props for #ident<#(#builder_args),*> #builder_where {
+            type Builder = #builder<#(#builder_args,)* #(#markers::#required),*>;
+
+            #[inline]
+            fn builder () -> Self::Builder {
+                return Self::builder()
+            }
+        }
+
+        impl #setter_impl_generics #builder<#(#args,)* #(#states),*> #setter_where {
+            #(#setters)*
+
+            /// Builds the component. Every required prop must have been set
+            #[inline]
+            pub fn build (self) -> #ident<#(#args),*> where #(#states: dynui::component::IsSet),* {
+                return #ident {
+                    #(#values),*
+                }
+            }
+        }
+    }
+}
+
+/// Returns the argument that refers to the generic `param`
+fn args_of (param: &GenericParam) -> TokenStream {
+    match param {
+        GenericParam::Lifetime(x) => x.lifetime.to_token_stream(),
+        GenericParam::Type(x) => x.ident.to_token_stream(),
+        GenericParam::Const(x) => x.ident.to_token_stream()
+    }
+}
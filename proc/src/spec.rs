@@ -0,0 +1,244 @@
+//! Element metadata vendored from the HTML Living Standard's indices
+//! (<https://html.spec.whatwg.org/multipage/indices.html>) and SVG 2's element and attribute indices
+//! (<https://www.w3.org/TR/SVG2/eltindex.html>, <https://www.w3.org/TR/SVG2/attindex.html>).
+//! Event handler attributes (`on*`) are valid on every element, so they're listed on their own.
+//! Elements are sorted by tag, so they can be binary searched.
+
+/// Element of the HTML or SVG specs
+pub struct ElementSpec {
+    pub tag: &'static str,
+    /// Name of the element's `web_sys` interface
+    pub interface: &'static str,
+    /// Whether the element can't have any children (i.e. `<input>`)
+    pub void: bool,
+    /// Attributes the element allows, besides the global ones
+    pub attributes: &'static [&'static str]
+}
+
+/// Attributes allowed on every HTML element
+pub static HTML_GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey", "autocapitalize", "autocorrect", "autofocus", "class", "contenteditable", "dir", "draggable",
+    "enterkeyhint", "hidden", "id", "inert", "inputmode", "is", "itemid", "itemprop", "itemref", "itemscope",
+    "itemtype", "lang", "nonce", "popover", "role", "slot", "spellcheck", "style", "tabindex", "title", "translate",
+    "writingsuggestions"
+];
+
+/// Event handler attributes, allowed on every HTML and SVG element.
+/// Besides the HTML spec's, it includes the ones added to `GlobalEventHandlers` by the CSS animations and transitions,
+/// pointer events, selection and touch events specs
+pub static EVENT_HANDLER_ATTRIBUTES: &[&str] = &[
+    "onafterprint", "onanimationcancel", "onanimationend", "onanimationiteration", "onanimationstart", "onauxclick",
+    "onbeforeinput", "onbeforematch", "onbeforeprint", "onbeforetoggle", "onbeforeunload", "onblur", "oncancel",
+    "oncanplay", "oncanplaythrough", "onchange", "onclick", "onclose", "oncommand", "oncontextlost", "oncontextmenu",
+    "oncontextrestored", "oncopy", "oncuechange", "oncut", "ondblclick", "ondrag", "ondragend", "ondragenter",
+    "ondragleave", "ondragover", "ondragstart", "ondrop", "ondurationchange", "onemptied", "onended", "onerror",
+    "onfocus", "onformdata", "ongotpointercapture", "onhashchange", "oninput", "oninvalid", "onkeydown", "onkeypress",
+    "onkeyup", "onlanguagechange", "onload", "onloadeddata", "onloadedmetadata", "onloadstart", "onlostpointercapture",
+    "onmessage", "onmessageerror", "onmousedown", "onmouseenter", "onmouseleave", "onmousemove", "onmouseout",
+    "onmouseover", "onmouseup", "onoffline", "ononline", "onpagehide", "onpagereveal", "onpageshow", "onpageswap",
+    "onpaste", "onpause", "onplay", "onplaying", "onpointercancel", "onpointerdown", "onpointerenter",
+    "onpointerleave", "onpointermove", "onpointerout", "onpointerover", "onpointerrawupdate", "onpointerup",
+    "onpopstate", "onprogress", "onratechange", "onrejectionhandled", "onreset", "onresize", "onscroll", "onscrollend",
+    "onsecuritypolicyviolation", "onseeked", "onseeking", "onselect", "onselectionchange", "onselectstart",
+    "onslotchange", "onstalled", "onstorage", "onsubmit", "onsuspend", "ontimeupdate", "ontoggle", "ontouchcancel",
+    "ontouchend", "ontouchmove", "ontouchstart", "ontransitioncancel", "ontransitionend", "ontransitionrun",
+    "ontransitionstart", "onunhandledrejection", "onunload", "onvolumechange", "onwaiting", "onwheel"
+];
+
+pub static HTML_ELEMENTS: &[ElementSpec] = &[
+    ElementSpec { tag: "a", interface: "HtmlAnchorElement", void: false, attributes: &["href", "target", "download", "ping", "rel", "hreflang", "type", "referrerpolicy"] },
+    ElementSpec { tag: "abbr", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "address", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "area", interface: "HtmlAreaElement", void: true, attributes: &["alt", "coords", "shape", "href", "target", "download", "ping", "rel", "referrerpolicy"] },
+    ElementSpec { tag: "article", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "aside", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "audio", interface: "HtmlAudioElement", void: false, attributes: &["src", "crossorigin", "preload", "autoplay", "loop", "muted", "controls"] },
+    ElementSpec { tag: "b", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "base", interface: "HtmlBaseElement", void: true, attributes: &["href", "target"] },
+    ElementSpec { tag: "bdi", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "bdo", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "blockquote", interface: "HtmlQuoteElement", void: false, attributes: &["cite"] },
+    ElementSpec { tag: "body", interface: "HtmlBodyElement", void: false, attributes: &[] },
+    ElementSpec { tag: "br", interface: "HtmlBrElement", void: true, attributes: &[] },
+    ElementSpec { tag: "button", interface: "HtmlButtonElement", void: false, attributes: &["command", "commandfor", "disabled", "form", "formaction", "formenctype", "formmethod", "formnovalidate", "formtarget", "name", "popovertarget", "popovertargetaction", "type", "value"] },
+    ElementSpec { tag: "canvas", interface: "HtmlCanvasElement", void: false, attributes: &["width", "height"] },
+    ElementSpec { tag: "caption", interface: "HtmlTableCaptionElement", void: false, attributes: &[] },
+    ElementSpec { tag: "cite", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "code", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "col", interface: "HtmlTableColElement", void: true, attributes: &["span"] },
+    ElementSpec { tag: "colgroup", interface: "HtmlTableColElement", void: false, attributes: &["span"] },
+    ElementSpec { tag: "data", interface: "HtmlDataElement", void: false, attributes: &["value"] },
+    ElementSpec { tag: "datalist", interface: "HtmlDataListElement", void: false, attributes: &[] },
+    ElementSpec { tag: "dd", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "del", interface: "HtmlModElement", void: false, attributes: &["cite", "datetime"] },
+    ElementSpec { tag: "details", interface: "HtmlDetailsElement", void: false, attributes: &["name", "open"] },
+    ElementSpec { tag: "dfn", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "dialog", interface: "HtmlDialogElement", void: false, attributes: &["closedby", "open"] },
+    ElementSpec { tag: "div", interface: "HtmlDivElement", void: false, attributes: &[] },
+    ElementSpec { tag: "dl", interface: "HtmlDListElement", void: false, attributes: &[] },
+    ElementSpec { tag: "dt", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "em", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "embed", interface: "HtmlEmbedElement", void: true, attributes: &["src", "type", "width", "height"] },
+    ElementSpec { tag: "fieldset", interface: "HtmlFieldSetElement", void: false, attributes: &["disabled", "form", "name"] },
+    ElementSpec { tag: "figcaption", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "figure", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "footer", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "form", interface: "HtmlFormElement", void: false, attributes: &["accept-charset", "action", "autocomplete", "enctype", "method", "name", "novalidate", "rel", "target"] },
+    ElementSpec { tag: "h1", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "h2", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "h3", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "h4", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "h5", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "h6", interface: "HtmlHeadingElement", void: false, attributes: &[] },
+    ElementSpec { tag: "head", interface: "HtmlHeadElement", void: false, attributes: &[] },
+    ElementSpec { tag: "header", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "hgroup", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "hr", interface: "HtmlHrElement", void: true, attributes: &[] },
+    ElementSpec { tag: "html", interface: "HtmlHtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "i", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "iframe", interface: "HtmlIFrameElement", void: false, attributes: &["src", "srcdoc", "name", "sandbox", "allow", "allowfullscreen", "width", "height", "referrerpolicy", "loading"] },
+    ElementSpec { tag: "img", interface: "HtmlImageElement", void: true, attributes: &["alt", "src", "srcset", "sizes", "crossorigin", "usemap", "ismap", "width", "height", "referrerpolicy", "decoding", "loading", "fetchpriority"] },
+    ElementSpec { tag: "input", interface: "HtmlInputElement", void: true, attributes: &["accept", "alpha", "alt", "autocomplete", "checked", "colorspace", "dirname", "disabled", "form", "formaction", "formenctype", "formmethod", "formnovalidate", "formtarget", "height", "list", "max", "maxlength", "min", "minlength", "multiple", "name", "pattern", "placeholder", "popovertarget", "popovertargetaction", "readonly", "required", "size", "src", "step", "type", "value", "width"] },
+    ElementSpec { tag: "ins", interface: "HtmlModElement", void: false, attributes: &["cite", "datetime"] },
+    ElementSpec { tag: "kbd", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "label", interface: "HtmlLabelElement", void: false, attributes: &["for"] },
+    ElementSpec { tag: "legend", interface: "HtmlLegendElement", void: false, attributes: &[] },
+    ElementSpec { tag: "li", interface: "HtmlLiElement", void: false, attributes: &["value"] },
+    ElementSpec { tag: "link", interface: "HtmlLinkElement", void: true, attributes: &["href", "crossorigin", "rel", "as", "media", "hreflang", "type", "sizes", "imagesrcset", "imagesizes", "referrerpolicy", "integrity", "blocking", "color", "disabled", "fetchpriority"] },
+    ElementSpec { tag: "main", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "map", interface: "HtmlMapElement", void: false, attributes: &["name"] },
+    ElementSpec { tag: "mark", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "menu", interface: "HtmlMenuElement", void: false, attributes: &[] },
+    ElementSpec { tag: "meta", interface: "HtmlMetaElement", void: true, attributes: &["name", "http-equiv", "content", "charset", "media"] },
+    ElementSpec { tag: "meter", interface: "HtmlMeterElement", void: false, attributes: &["value", "min", "max", "low", "high", "optimum"] },
+    ElementSpec { tag: "nav", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "noscript", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "object", interface: "HtmlObjectElement", void: false, attributes: &["data", "type", "name", "form", "width", "height"] },
+    ElementSpec { tag: "ol", interface: "HtmlOListElement", void: false, attributes: &["reversed", "start", "type"] },
+    ElementSpec { tag: "optgroup", interface: "HtmlOptGroupElement", void: false, attributes: &["disabled", "label"] },
+    ElementSpec { tag: "option", interface: "HtmlOptionElement", void: false, attributes: &["disabled", "label", "selected", "value"] },
+    ElementSpec { tag: "output", interface: "HtmlOutputElement", void: false, attributes: &["for", "form", "name"] },
+    ElementSpec { tag: "p", interface: "HtmlParagraphElement", void: false, attributes: &[] },
+    ElementSpec { tag: "picture", interface: "HtmlPictureElement", void: false, attributes: &[] },
+    ElementSpec { tag: "pre", interface: "HtmlPreElement", void: false, attributes: &[] },
+    ElementSpec { tag: "progress", interface: "HtmlProgressElement", void: false, attributes: &["value", "max"] },
+    ElementSpec { tag: "q", interface: "HtmlQuoteElement", void: false, attributes: &["cite"] },
+    ElementSpec { tag: "rp", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "rt", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "ruby", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "s", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "samp", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "script", interface: "HtmlScriptElement", void: false, attributes: &["src", "type", "nomodule", "async", "defer", "crossorigin", "integrity", "referrerpolicy", "blocking", "fetchpriority"] },
+    ElementSpec { tag: "search", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "section", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "select", interface: "HtmlSelectElement", void: false, attributes: &["autocomplete", "disabled", "form", "multiple", "name", "required", "size"] },
+    ElementSpec { tag: "slot", interface: "HtmlSlotElement", void: false, attributes: &["name"] },
+    ElementSpec { tag: "small", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "source", interface: "HtmlSourceElement", void: true, attributes: &["type", "media", "src", "srcset", "sizes", "width", "height"] },
+    ElementSpec { tag: "span", interface: "HtmlSpanElement", void: false, attributes: &[] },
+    ElementSpec { tag: "strong", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "style", interface: "HtmlStyleElement", void: false, attributes: &["media", "blocking"] },
+    ElementSpec { tag: "sub", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "summary", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "sup", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "table", interface: "HtmlTableElement", void: false, attributes: &[] },
+    ElementSpec { tag: "tbody", interface: "HtmlTableSectionElement", void: false, attributes: &[] },
+    ElementSpec { tag: "td", interface: "HtmlTableCellElement", void: false, attributes: &["colspan", "rowspan", "headers"] },
+    ElementSpec { tag: "template", interface: "HtmlTemplateElement", void: false, attributes: &["shadowrootmode", "shadowrootdelegatesfocus", "shadowrootclonable", "shadowrootserializable"] },
+    ElementSpec { tag: "textarea", interface: "HtmlTextAreaElement", void: false, attributes: &["autocomplete", "cols", "dirname", "disabled", "form", "maxlength", "minlength", "name", "placeholder", "readonly", "required", "rows", "wrap"] },
+    ElementSpec { tag: "tfoot", interface: "HtmlTableSectionElement", void: false, attributes: &[] },
+    ElementSpec { tag: "th", interface: "HtmlTableCellElement", void: false, attributes: &["colspan", "rowspan", "headers", "scope", "abbr"] },
+    ElementSpec { tag: "thead", interface: "HtmlTableSectionElement", void: false, attributes: &[] },
+    ElementSpec { tag: "time", interface: "HtmlTimeElement", void: false, attributes: &["datetime"] },
+    ElementSpec { tag: "title", interface: "HtmlTitleElement", void: false, attributes: &[] },
+    ElementSpec { tag: "tr", interface: "HtmlTableRowElement", void: false, attributes: &[] },
+    ElementSpec { tag: "track", interface: "HtmlTrackElement", void: true, attributes: &["default", "kind", "label", "src", "srclang"] },
+    ElementSpec { tag: "u", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "ul", interface: "HtmlUListElement", void: false, attributes: &[] },
+    ElementSpec { tag: "var", interface: "HtmlElement", void: false, attributes: &[] },
+    ElementSpec { tag: "video", interface: "HtmlVideoElement", void: false, attributes: &["src", "crossorigin", "poster", "preload", "autoplay", "playsinline", "loop", "muted", "controls", "width", "height"] },
+    ElementSpec { tag: "wbr", interface: "HtmlElement", void: true, attributes: &[] },
+];
+
+/// Attributes allowed on every SVG element, including it's presentation attributes
+pub static SVG_GLOBAL_ATTRIBUTES: &[&str] = &[
+    "id", "lang", "tabindex", "class", "style", "autofocus", "requiredExtensions", "systemLanguage",
+    "alignment-baseline", "baseline-shift", "clip", "clip-path", "clip-rule", "color", "color-interpolation",
+    "color-interpolation-filters", "color-rendering", "cursor", "direction", "display", "dominant-baseline", "fill",
+    "fill-opacity", "fill-rule", "filter", "flood-color", "flood-opacity", "font", "font-family",
+    "font-feature-settings", "font-kerning", "font-size", "font-size-adjust", "font-stretch", "font-style",
+    "font-variant", "font-weight", "glyph-orientation-horizontal", "glyph-orientation-vertical", "image-rendering",
+    "inline-size", "isolation", "letter-spacing", "lighting-color", "line-height", "marker", "marker-end",
+    "marker-mid", "marker-start", "mask", "mask-type", "mix-blend-mode", "opacity", "overflow", "paint-order",
+    "pointer-events", "shape-image-threshold", "shape-inside", "shape-margin", "shape-padding", "shape-rendering",
+    "shape-subtract", "stop-color", "stop-opacity", "stroke", "stroke-dasharray", "stroke-dashoffset",
+    "stroke-linecap", "stroke-linejoin", "stroke-miterlimit", "stroke-opacity", "stroke-width", "text-anchor",
+    "text-decoration", "text-overflow", "text-rendering", "transform", "transform-box", "transform-origin",
+    "unicode-bidi", "vector-effect", "visibility", "white-space", "word-spacing", "writing-mode"
+];
+
+pub static SVG_ELEMENTS: &[ElementSpec] = &[
+    ElementSpec { tag: "a", interface: "SvgaElement", void: false, attributes: &["href", "target", "download", "ping", "rel", "hreflang", "type", "referrerpolicy"] },
+    ElementSpec { tag: "animate", interface: "SvgAnimateElement", void: false, attributes: &["href", "attributeName", "begin", "dur", "end", "min", "max", "restart", "repeatCount", "repeatDur", "fill", "calcMode", "values", "keyTimes", "keySplines", "from", "to", "by", "additive", "accumulate"] },
+    ElementSpec { tag: "animateMotion", interface: "SvgAnimateMotionElement", void: false, attributes: &["href", "attributeName", "begin", "dur", "end", "min", "max", "restart", "repeatCount", "repeatDur", "fill", "calcMode", "values", "keyTimes", "keySplines", "from", "to", "by", "additive", "accumulate", "path", "keyPoints", "rotate", "origin"] },
+    ElementSpec { tag: "animateTransform", interface: "SvgAnimateTransformElement", void: false, attributes: &["href", "attributeName", "begin", "dur", "end", "min", "max", "restart", "repeatCount", "repeatDur", "fill", "calcMode", "values", "keyTimes", "keySplines", "from", "to", "by", "additive", "accumulate", "type"] },
+    ElementSpec { tag: "circle", interface: "SvgCircleElement", void: false, attributes: &["cx", "cy", "r", "pathLength"] },
+    ElementSpec { tag: "clipPath", interface: "SvgClipPathElement", void: false, attributes: &["clipPathUnits"] },
+    ElementSpec { tag: "defs", interface: "SvgDefsElement", void: false, attributes: &[] },
+    ElementSpec { tag: "desc", interface: "SvgDescElement", void: false, attributes: &[] },
+    ElementSpec { tag: "ellipse", interface: "SvgEllipseElement", void: false, attributes: &["cx", "cy", "rx", "ry", "pathLength"] },
+    ElementSpec { tag: "feBlend", interface: "SvgfeBlendElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "in2", "mode"] },
+    ElementSpec { tag: "feColorMatrix", interface: "SvgfeColorMatrixElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "type", "values"] },
+    ElementSpec { tag: "feComponentTransfer", interface: "SvgfeComponentTransferElement", void: false, attributes: &["x", "y", "width", "height", "result", "in"] },
+    ElementSpec { tag: "feComposite", interface: "SvgfeCompositeElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "in2", "operator", "k1", "k2", "k3", "k4"] },
+    ElementSpec { tag: "feConvolveMatrix", interface: "SvgfeConvolveMatrixElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "order", "kernelMatrix", "divisor", "bias", "targetX", "targetY", "edgeMode", "kernelUnitLength", "preserveAlpha"] },
+    ElementSpec { tag: "feDiffuseLighting", interface: "SvgfeDiffuseLightingElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "diffuseConstant", "kernelUnitLength", "surfaceScale"] },
+    ElementSpec { tag: "feDisplacementMap", interface: "SvgfeDisplacementMapElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "in2", "scale", "xChannelSelector", "yChannelSelector"] },
+    ElementSpec { tag: "feDistantLight", interface: "SvgfeDistantLightElement", void: false, attributes: &["azimuth", "elevation"] },
+    ElementSpec { tag: "feDropShadow", interface: "SvgfeDropShadowElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "dx", "dy", "stdDeviation"] },
+    ElementSpec { tag: "feFlood", interface: "SvgfeFloodElement", void: false, attributes: &["x", "y", "width", "height", "result"] },
+    ElementSpec { tag: "feFuncA", interface: "SvgfeFuncAElement", void: false, attributes: &["type", "tableValues", "slope", "intercept", "amplitude", "exponent", "offset"] },
+    ElementSpec { tag: "feFuncB", interface: "SvgfeFuncBElement", void: false, attributes: &["type", "tableValues", "slope", "intercept", "amplitude", "exponent", "offset"] },
+    ElementSpec { tag: "feFuncG", interface: "SvgfeFuncGElement", void: false, attributes: &["type", "tableValues", "slope", "intercept", "amplitude", "exponent", "offset"] },
+    ElementSpec { tag: "feFuncR", interface: "SvgfeFuncRElement", void: false, attributes: &["type", "tableValues", "slope", "intercept", "amplitude", "exponent", "offset"] },
+    ElementSpec { tag: "feGaussianBlur", interface: "SvgfeGaussianBlurElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "stdDeviation", "edgeMode"] },
+    ElementSpec { tag: "feImage", interface: "SvgfeImageElement", void: false, attributes: &["x", "y", "width", "height", "result", "href", "preserveAspectRatio", "crossorigin"] },
+    ElementSpec { tag: "feMerge", interface: "SvgfeMergeElement", void: false, attributes: &["x", "y", "width", "height", "result"] },
+    ElementSpec { tag: "feMergeNode", interface: "SvgfeMergeNodeElement", void: false, attributes: &["in"] },
+    ElementSpec { tag: "feMorphology", interface: "SvgfeMorphologyElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "operator", "radius"] },
+    ElementSpec { tag: "feOffset", interface: "SvgfeOffsetElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "dx", "dy"] },
+    ElementSpec { tag: "fePointLight", interface: "SvgfePointLightElement", void: false, attributes: &["x", "y", "z"] },
+    ElementSpec { tag: "feSpecularLighting", interface: "SvgfeSpecularLightingElement", void: false, attributes: &["x", "y", "width", "height", "result", "in", "specularConstant", "specularExponent", "kernelUnitLength", "surfaceScale"] },
+    ElementSpec { tag: "feSpotLight", interface: "SvgfeSpotLightElement", void: false, attributes: &["x", "y", "z", "pointsAtX", "pointsAtY", "pointsAtZ", "specularExponent", "limitingConeAngle"] },
+    ElementSpec { tag: "feTile", interface: "SvgfeTileElement", void: false, attributes: &["x", "y", "width", "height", "result", "in"] },
+    ElementSpec { tag: "feTurbulence", interface: "SvgfeTurbulenceElement", void: false, attributes: &["x", "y", "width", "height", "result", "baseFrequency", "numOctaves", "seed", "stitchTiles", "type"] },
+    ElementSpec { tag: "filter", interface: "SvgFilterElement", void: false, attributes: &["x", "y", "width", "height", "filterUnits", "primitiveUnits"] },
+    ElementSpec { tag: "foreignObject", interface: "SvgForeignObjectElement", void: false, attributes: &["x", "y", "width", "height"] },
+    ElementSpec { tag: "g", interface: "SvggElement", void: false, attributes: &[] },
+    ElementSpec { tag: "image", interface: "SvgImageElement", void: false, attributes: &["x", "y", "width", "height", "href", "preserveAspectRatio", "crossorigin", "decoding"] },
+    ElementSpec { tag: "line", interface: "SvgLineElement", void: false, attributes: &["x1", "y1", "x2", "y2", "pathLength"] },
+    ElementSpec { tag: "linearGradient", interface: "SvgLinearGradientElement", void: false, attributes: &["x1", "y1", "x2", "y2", "gradientUnits", "gradientTransform", "spreadMethod", "href"] },
+    ElementSpec { tag: "marker", interface: "SvgMarkerElement", void: false, attributes: &["viewBox", "preserveAspectRatio", "refX", "refY", "markerUnits", "markerWidth", "markerHeight", "orient"] },
+    ElementSpec { tag: "mask", interface: "SvgMaskElement", void: false, attributes: &["x", "y", "width", "height", "maskUnits", "maskContentUnits"] },
+    ElementSpec { tag: "metadata", interface: "SvgMetadataElement", void: false, attributes: &[] },
+    ElementSpec { tag: "mpath", interface: "SvgmPathElement", void: false, attributes: &["href"] },
+    ElementSpec { tag: "path", interface: "SvgPathElement", void: false, attributes: &["d", "pathLength"] },
+    ElementSpec { tag: "pattern", interface: "SvgPatternElement", void: false, attributes: &["viewBox", "preserveAspectRatio", "x", "y", "width", "height", "patternUnits", "patternContentUnits", "patternTransform", "href"] },
+    ElementSpec { tag: "polygon", interface: "SvgPolygonElement", void: false, attributes: &["points", "pathLength"] },
+    ElementSpec { tag: "polyline", interface: "SvgPolylineElement", void: false, attributes: &["points", "pathLength"] },
+    ElementSpec { tag: "radialGradient", interface: "SvgRadialGradientElement", void: false, attributes: &["cx", "cy", "r", "fx", "fy", "fr", "gradientUnits", "gradientTransform", "spreadMethod", "href"] },
+    ElementSpec { tag: "rect", interface: "SvgRectElement", void: false, attributes: &["x", "y", "width", "height", "rx", "ry", "pathLength"] },
+    ElementSpec { tag: "script", interface: "SvgScriptElement", void: false, attributes: &["type", "href", "crossorigin"] },
+    ElementSpec { tag: "set", interface: "SvgSetElement", void: false, attributes: &["href", "attributeName", "begin", "dur", "end", "min", "max", "restart", "repeatCount", "repeatDur", "fill", "to"] },
+    ElementSpec { tag: "stop", interface: "SvgStopElement", void: false, attributes: &["offset"] },
+    ElementSpec { tag: "style", interface: "SvgStyleElement", void: false, attributes: &["type", "media", "title"] },
+    ElementSpec { tag: "svg", interface: "SvgsvgElement", void: false, attributes: &["viewBox", "preserveAspectRatio", "x", "y", "width", "height"] },
+    ElementSpec { tag: "switch", interface: "SvgSwitchElement", void: false, attributes: &[] },
+    ElementSpec { tag: "symbol", interface: "SvgSymbolElement", void: false, attributes: &["viewBox", "preserveAspectRatio", "refX", "refY", "x", "y", "width", "height"] },
+    ElementSpec { tag: "text", interface: "SvgTextElement", void: false, attributes: &["x", "y", "dx", "dy", "rotate", "textLength", "lengthAdjust"] },
+    ElementSpec { tag: "textPath", interface: "SvgTextPathElement", void: false, attributes: &["href", "path", "method", "spacing", "startOffset", "textLength", "lengthAdjust", "side"] },
+    ElementSpec { tag: "title", interface: "SvgTitleElement", void: false, attributes: &[] },
+    ElementSpec { tag: "tspan", interface: "SvgtSpanElement", void: false, attributes: &["x", "y", "dx", "dy", "rotate", "textLength", "lengthAdjust"] },
+    ElementSpec { tag: "use", interface: "SvgUseElement", void: false, attributes: &["href", "x", "y", "width", "height"] },
+    ElementSpec { tag: "view", interface: "SvgViewElement", void: false, attributes: &["viewBox", "preserveAspectRatio"] },
+];
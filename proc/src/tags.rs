@@ -0,0 +1,241 @@
+use syn::{Ident, ext::IdentExt};
+use crate::html::AttrName;
+use crate::spec::{ElementSpec, EVENT_HANDLER_ATTRIBUTES, HTML_ELEMENTS, HTML_GLOBAL_ATTRIBUTES, SVG_ELEMENTS, SVG_GLOBAL_ATTRIBUTES};
+
+/// Namespace an element is created in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    Html,
+    Svg,
+    MathMl
+}
+
+impl Namespace {
+    /// Returns the namespace of the element `tag`, created inside an element of this namespace
+    #[inline]
+    pub fn element (self, tag: &str) -> Self {
+        match tag {
+            "svg" => Self::Svg,
+            "math" => Self::MathMl,
+            _ => self
+        }
+    }
+
+    /// Returns the namespace of the children of the element `tag`, which belongs to this namespace.
+    /// The contents of SVG's `<foreignObject>` are HTML again
+    #[inline]
+    pub fn children (self, tag: &str) -> Self {
+        match (self, tag) {
+            (Self::Svg, "foreignObject") => Self::Html,
+            _ => self
+        }
+    }
+
+    /// Returns the namespace's URI, if elements must be created with it (HTML elements aren't)
+    #[inline]
+    pub fn uri (self) -> Option<&'static str> {
+        match self {
+            Self::Html => None,
+            Self::Svg => Some("http://www.w3.org/2000/svg"),
+            Self::MathMl => Some("http://www.w3.org/1998/Math/MathML")
+        }
+    }
+
+    /// Returns the name of the `web_sys` interface of the element `tag` in this namespace.
+    /// Elements without a spec get the namespace's base interface, which only happens to MathML ones,
+    /// since unknown HTML and SVG tags are rejected by [`validate_tag`]
+    #[inline]
+    pub fn interface (self, tag: &str) -> &'static str {
+        match (self, self.spec(tag)) {
+            (_, Some(spec)) => spec.interface,
+            (Self::Html, None) => "HtmlElement",
+            (Self::Svg, None) => "SvgElement",
+            (Self::MathMl, None) => "MathMlElement"
+        }
+    }
+
+    /// Returns the spec of the element `tag` in this namespace, if it's a known one.
+    /// MathML elements aren't part of the dataset
+    #[inline]
+    pub fn spec (self, tag: &str) -> Option<&'static ElementSpec> {
+        let elements = self.elements()?;
+        return elements.binary_search_by(|x| x.tag.cmp(tag)).ok().map(|i| &elements[i])
+    }
+
+    #[inline]
+    fn elements (self) -> Option<&'static [ElementSpec]> {
+        match self {
+            Self::Html => Some(HTML_ELEMENTS),
+            Self::Svg => Some(SVG_ELEMENTS),
+            Self::MathMl => None
+        }
+    }
+
+    #[inline]
+    fn global_attributes (self) -> &'static [&'static str] {
+        match self {
+            Self::Html => HTML_GLOBAL_ATTRIBUTES,
+            Self::Svg => SVG_GLOBAL_ATTRIBUTES,
+            Self::MathMl => &[]
+        }
+    }
+
+    #[inline]
+    fn name (self) -> &'static str {
+        match self {
+            Self::Html => "HTML",
+            Self::Svg => "SVG",
+            Self::MathMl => "MathML"
+        }
+    }
+}
+
+/// Checks that `tag` is a known element of the namespace `ns`. MathML elements aren't checked
+pub fn validate_tag (ns: Namespace, tag: &Ident) -> syn::Result<()> {
+    let name = tag.unraw().to_string();
+    let Some(elements) = ns.elements() else { return Ok(()) };
+    if ns.spec(&name).is_some() {
+        return Ok(())
+    }
+
+    let mut msg = format!("unknown {} element `<{name}>`", ns.name());
+    if let Some(x) = suggest(&name, elements.iter().map(|x| x.tag)) {
+        msg.push_str(&format!(". did you mean `<{x}>`?"))
+    }
+    return Err(syn::Error::new_spanned(tag, msg))
+}
+
+/// Checks that the element `tag` of the namespace `ns` allows the attribute `name`.
+/// Attributes named by string literals are never checked, so they can be used to set any other attribute,
+/// and neither are `data-*` and `aria-*` attributes, or the attributes of unknown elements.
+/// Event handler attributes (i.e. `onclick`) are allowed on every element
+pub fn validate_attribute (ns: Namespace, tag: &str, name: &AttrName) -> syn::Result<()> {
+    let AttrName::Ident(_) = name else { return Ok(()) };
+    let Some(spec) = ns.spec(tag) else { return Ok(()) };
+
+    // `ref` fills the element's `NodeRef` instead of setting an attribute
+    if name.as_ident().is_some_and(|x| x == "ref") {
+        return Ok(())
+    }
+
+    let value = name.value();
+    if value.starts_with("data-") || value.starts_with("aria-") {
+        return Ok(())
+    }
+
+    let allowed = || spec.attributes.iter().chain(ns.global_attributes()).chain(EVENT_HANDLER_ATTRIBUTES).copied();
+    if allowed().any(|x| x == value) {
+        return Ok(())
+    }
+
+    let mut msg = format!("unknown attribute `{value}` on `<{tag}>`");
+    match suggest(&value, allowed()) {
+        Some(x) => msg.push_str(&format!(". did you mean `{x}`?")),
+        None => msg.push_str(&format!(". name it with a string literal (`\"{value}\"={{...}}`) to set it anyway"))
+    }
+    return Err(syn::Error::new_spanned(name, msg))
+}
+
+/// Checks that the element `tag` of the namespace `ns` may have children
+pub fn validate_children (ns: Namespace, tag: &Ident) -> syn::Result<()> {
+    match ns.spec(&tag.unraw().to_string()) {
+        Some(spec) if spec.void => Err(syn::Error::new_spanned(tag, format!("`<{}>` is a void element, and can't have children", spec.tag))),
+        _ => Ok(())
+    }
+}
+
+/// Returns the candidate closest to `name`, if it's close enough to be a likely misspelling
+fn suggest<'a> (name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max = usize::max(1, name.len() / 3);
+    return candidates.into_iter()
+        .map(|x| (distance(name, x), x))
+        .filter(|(d, _)| *d <= max)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, x)| x)
+}
+
+/// Edit distance between `a` and `b`, counting transpositions of adjacent characters as a single edit
+fn distance (a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            row[j] = (rows[i - 1][j] + 1).min(row[j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1)
+            }
+        }
+        rows.push(row);
+    }
+
+    return rows[a.len()][b.len()]
+}
+
+/// Returns the URI of the namespace of attributes prefixed by `prefix` (i.e. `xlink:href`), if it's a known one
+pub fn attribute_namespace (prefix: &str) -> Option<&'static str> {
+    return match prefix {
+        "xlink" => Some("http://www.w3.org/1999/xlink"),
+        "xml" => Some("http://www.w3.org/XML/1998/namespace"),
+        "xmlns" => Some("http://www.w3.org/2000/xmlns/"),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance () {
+        assert_eq!(super::distance("div", "div"), 0);
+        assert_eq!(super::distance("", "span"), 4);
+        assert_eq!(super::distance("dvi", "div"), 1);
+        assert_eq!(super::distance("clas", "class"), 1);
+        assert_eq!(super::distance("heigth", "height"), 1);
+        assert_eq!(super::distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggest () {
+        let tags = HTML_ELEMENTS.iter().map(|x| x.tag);
+        assert_eq!(super::suggest("buton", tags.clone()), Some("button"));
+        assert_eq!(super::suggest("spna", tags.clone()), Some("span"));
+        assert_eq!(super::suggest("xyzzy", tags), None);
+
+        let attributes = ["href", "target", "download"];
+        assert_eq!(super::suggest("hrfe", attributes), Some("href"));
+        assert_eq!(super::suggest("src", attributes), None);
+    }
+
+    #[test]
+    fn event_handlers () {
+        let validate = |ns, tag, name| validate_attribute(ns, tag, &syn::parse_str::<AttrName>(name).unwrap());
+        assert!(validate(Namespace::Html, "button", "onclick").is_ok());
+        assert!(validate(Namespace::Html, "div", "onpointerdown").is_ok());
+        assert!(validate(Namespace::Svg, "circle", "onclick").is_ok());
+
+        let e = validate(Namespace::Html, "button", "onclcik").unwrap_err();
+        assert_eq!(e.to_string(), "unknown attribute `onclcik` on `<button>`. did you mean `onclick`?");
+        assert!(validate(Namespace::Html, "button", "one").is_err());
+    }
+
+    /// [`Namespace::spec`] binary searches the elements by tag
+    #[test]
+    fn sorted () {
+        for elements in [HTML_ELEMENTS, SVG_ELEMENTS] {
+            assert!(elements.windows(2).all(|x| x[0].tag < x[1].tag));
+        }
+    }
+
+    #[test]
+    fn interface () {
+        assert_eq!(Namespace::Html.interface("input"), "HtmlInputElement");
+        assert_eq!(Namespace::Html.interface("section"), "HtmlElement");
+        assert_eq!(Namespace::Svg.interface("circle"), "SvgCircleElement");
+        assert_eq!(Namespace::MathMl.interface("mi"), "MathMlElement");
+    }
+}
@@ -0,0 +1,13 @@
+use dynui::{*, macros::*, component::*};
+
+#[component]
+fn Greeting (name: &'static str, #[prop(default)] excited: bool) -> Result<Node> {
+    match excited {
+        true => html! { <p>"Hello " {name} "!"</p> }?.render(),
+        false => html! { <p>"Hello " {name}</p> }?.render()
+    }
+}
+
+fn main () {
+    let _ = html! { <Greeting excited={true} /> };
+}
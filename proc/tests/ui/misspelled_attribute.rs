@@ -0,0 +1,5 @@
+use dynui::macros::*;
+
+fn main () {
+    let _ = html! { <a hrfe={"/"}>"Home"</a> };
+}
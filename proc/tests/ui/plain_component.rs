@@ -0,0 +1,15 @@
+use dynui::{*, macros::*, component::*};
+
+struct Greeting {
+    name: &'static str
+}
+
+impl Component for Greeting {
+    fn render (self) -> Result<Node> {
+        html! { <p>"Hello " {self.name}</p> }?.render()
+    }
+}
+
+fn main () {
+    let _ = html! { <Greeting name={"world"} /> };
+}
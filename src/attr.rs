@@ -1,89 +1,335 @@
-use std::{rc::Rc, sync::Arc};
-use crate::Result;
+use std::{rc::Rc, sync::Arc, borrow::Cow, ops::Deref, collections::{HashMap, BTreeMap}};
+use crate::{Result, CONTEXT, component::Element};
+
+const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// An attribute node bound to the element it belongs to.
+/// The node may be added to and removed from the element any number of times, keeping it's identity.
+#[derive(Debug, Clone)]
+pub struct AttrNode {
+    element: web_sys::Element,
+    attr: web_sys::Attr
+}
+
+impl AttrNode {
+    /// Creates a new attribute node with the specified `name`, without adding it to `element`.
+    /// Names are only lowercased for HTML elements, so that SVG and MathML attributes like `viewBox` keep their case
+    #[inline]
+    pub fn new (element: web_sys::Element, name: &str) -> Result<Self> {
+        if element.namespace_uri().as_deref() != Some(HTML_NAMESPACE) {
+            return Self::new_ns(element, None, name)
+        }
+
+        let attr = CONTEXT.with(|ctx| ctx.document.create_attribute(name))?;
+        return Ok(Self { element, attr })
+    }
+
+    /// Creates a new attribute node with the specified qualified `name`, inside the specified `namespace` (i.e. `xlink:href`),
+    /// without adding it to `element`
+    #[inline]
+    pub fn new_ns (element: web_sys::Element, namespace: Option<&str>, name: &str) -> Result<Self> {
+        let attr = CONTEXT.with(|ctx| ctx.document.create_attribute_ns(namespace, name))?;
+        return Ok(Self { element, attr })
+    }
+
+    /// Returns the element the attribute belongs to
+    #[inline(always)]
+    pub fn element (&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Returns `true` if the attribute is currently present on it's element
+    #[inline]
+    pub fn is_present (&self) -> bool {
+        self.element.get_attribute_node(&self.attr.name()).is_some_and(|x| x.is_same_node(Some(&self.attr)))
+    }
+
+    /// Sets the attribute's value, adding it to it's element if it wasn't present
+    #[inline]
+    pub fn set_value (&self, value: &str) -> Result<()> {
+        self.attr.set_value(value);
+        return self.add()
+    }
+
+    /// Adds the attribute to it's element, if it wasn't already present
+    #[inline]
+    pub fn add (&self) -> Result<()> {
+        if !self.is_present() {
+            self.element.set_attribute_node(&self.attr)?;
+        }
+        return Ok(())
+    }
+
+    /// Removes the attribute from it's element, if it was present
+    #[inline]
+    pub fn remove (&self) -> Result<()> {
+        if self.is_present() {
+            self.element.remove_attribute_node(&self.attr)?;
+        }
+        return Ok(())
+    }
+}
+
+impl Deref for AttrNode {
+    type Target = web_sys::Attr;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.attr
+    }
+}
 
 /// Attribute that can be rendered with ownership
 pub trait Attribute {
     /// Renders the value into the specified `attr`
-    fn render (self, attr: &web_sys::Attr) -> Result<()>;
+    fn render (self, attr: &AttrNode) -> Result<()>;
 }
 
 /// Attribute that can be rendered with a reference
 pub trait RefAttribute: MutAttribute {
     /// Renders the value into the specified `attr`
-    fn render (&self, attr: &web_sys::Attr) -> Result<()>;
+    fn render (&self, attr: &AttrNode) -> Result<()>;
+
+    /// Renders the current value into the specified `attr`, without tracking it's future changes (if it's a cell)
+    #[inline]
+    fn update (&self, attr: &AttrNode) -> Result<()> {
+        self.render(attr)
+    }
 }
 
 impl<T: ?Sized + RefAttribute> Attribute for &T {
     #[inline]
-    default fn render (self, attr: &web_sys::Attr) -> Result<()> {
+    default fn render (self, attr: &AttrNode) -> Result<()> {
         RefAttribute::render(self, attr)
     }
 }
 
 impl<T: ?Sized + RefAttribute> MutAttribute for T {
     #[inline]
-    default fn render (&mut self, attr: &web_sys::Attr) -> Result<()> {
+    default fn render (&mut self, attr: &AttrNode) -> Result<()> {
         RefAttribute::render(self, attr)
     }
 }
 
 impl RefAttribute for &str {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
     }
 }
 
 impl RefAttribute for str {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
     }
 }
 
 impl RefAttribute for String {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
     }
 }
 
 impl RefAttribute for Box<str> {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
     }
 }
 
 impl RefAttribute for Rc<str> {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
     }
 }
 
 impl RefAttribute for Arc<str> {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
-        attr.set_value(self);
-        Ok(())
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
+    }
+}
+
+impl RefAttribute for Cow<'_, str> {
+    #[inline]
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        attr.set_value(self)
+    }
+}
+
+impl RefAttribute for bool {
+    #[inline]
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        match self {
+            true => attr.set_value(""),
+            false => attr.remove()
+        }
     }
 }
 
+impl<T: RefAttribute> RefAttribute for Option<T> {
+    #[inline]
+    fn render (&self, attr: &AttrNode) -> Result<()> {
+        match self {
+            Some(x) => RefAttribute::render(x, attr),
+            None => attr.remove()
+        }
+    }
+
+    #[inline]
+    fn update (&self, attr: &AttrNode) -> Result<()> {
+        match self {
+            Some(x) => x.update(attr),
+            None => attr.remove()
+        }
+    }
+}
+
+impl<T: Attribute> Attribute for Option<T> {
+    #[inline]
+    fn render (self, attr: &AttrNode) -> Result<()> {
+        match self {
+            Some(x) => x.render(attr),
+            None => attr.remove()
+        }
+    }
+}
+
+macro_rules! impl_owned {
+    ($($t:ty),+) => {
+        $(
+            impl Attribute for $t {
+                #[inline]
+                fn render (self, attr: &AttrNode) -> Result<()> {
+                    RefAttribute::render(&self, attr)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_display {
+    ($($t:ty),+) => {
+        $(
+            impl RefAttribute for $t {
+                #[inline]
+                fn render (&self, attr: &AttrNode) -> Result<()> {
+                    attr.set_value(&self.to_string())
+                }
+            }
+        )+
+
+        impl_owned! { $($t),+ }
+    };
+}
+
+impl_owned! { bool, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+impl_display! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, char
+}
+
 /// Attribute that can be rendered with mutable reference
 pub trait MutAttribute {
     /// Renders the value into the specified `attr`
-    fn render (&mut self, attr: &web_sys::Attr) -> Result<()>;
+    fn render (&mut self, attr: &AttrNode) -> Result<()>;
 }
 
 impl<T: ?Sized + MutAttribute> Attribute for &mut T {
     #[inline]
-    default fn render (self, attr: &web_sys::Attr) -> Result<()> {
+    default fn render (self, attr: &AttrNode) -> Result<()> {
         MutAttribute::render(self, attr)
     }
+}
+
+/// Collection of attributes that can be spread into an element with ownership
+pub trait Attributes {
+    /// Renders every attribute of the collection into `element`
+    fn render (self, element: &Element) -> Result<()>;
+}
+
+/// Collection of attributes that can be spread into an element by reference
+pub trait RefAttributes {
+    /// Executes `f` for every attribute of the collection
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()>;
+
+    /// Renders every attribute of the collection into `element`
+    #[inline]
+    fn render (&self, element: &Element) -> Result<()> {
+        self.for_each(&mut |name, value| element.set_attribute(name, value).map(|_| ()))
+    }
+}
+
+impl<T: ?Sized + RefAttributes> Attributes for &T {
+    #[inline]
+    default fn render (self, element: &Element) -> Result<()> {
+        RefAttributes::render(self, element)
+    }
+}
+
+impl<K: AsRef<str>, V: RefAttribute> RefAttributes for [(K, V)] {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.iter().try_for_each(|(k, v)| f(k.as_ref(), v))
+    }
+}
+
+impl<K: AsRef<str>, V: RefAttribute, const N: usize> RefAttributes for [(K, V); N] {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.as_slice().for_each(f)
+    }
+}
+
+impl<K: AsRef<str>, V: RefAttribute> RefAttributes for Vec<(K, V)> {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.as_slice().for_each(f)
+    }
+}
+
+impl<K: AsRef<str>, V: RefAttribute, S> RefAttributes for HashMap<K, V, S> {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.iter().try_for_each(|(k, v)| f(k.as_ref(), v))
+    }
+}
+
+impl<K: AsRef<str>, V: RefAttribute> RefAttributes for BTreeMap<K, V> {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.iter().try_for_each(|(k, v)| f(k.as_ref(), v))
+    }
+}
+
+impl<K: AsRef<str>, V: Attribute> Attributes for Vec<(K, V)> {
+    #[inline]
+    fn render (self, element: &Element) -> Result<()> {
+        self.into_iter().try_for_each(|(k, v)| element.set_attribute(k.as_ref(), v).map(|_| ()))
+    }
+}
+
+impl<K: AsRef<str>, V: Attribute, const N: usize> Attributes for [(K, V); N] {
+    #[inline]
+    fn render (self, element: &Element) -> Result<()> {
+        self.into_iter().try_for_each(|(k, v)| element.set_attribute(k.as_ref(), v).map(|_| ()))
+    }
+}
+
+impl<K: AsRef<str>, V: Attribute, S> Attributes for HashMap<K, V, S> {
+    #[inline]
+    fn render (self, element: &Element) -> Result<()> {
+        self.into_iter().try_for_each(|(k, v)| element.set_attribute(k.as_ref(), v).map(|_| ()))
+    }
+}
+
+impl<K: AsRef<str>, V: Attribute> Attributes for BTreeMap<K, V> {
+    #[inline]
+    fn render (self, element: &Element) -> Result<()> {
+        self.into_iter().try_for_each(|(k, v)| element.set_attribute(k.as_ref(), v).map(|_| ()))
+    }
 }
\ No newline at end of file
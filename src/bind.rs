@@ -0,0 +1,162 @@
+use std::{rc::Rc, fmt::Display, ops::Deref};
+use into_string::FromString;
+use wasm_bindgen::{JsValue, JsCast};
+use crate::{Result, cell::MutableCell, component::Element, owner::Owner, prop::RefProperty, jseprintln};
+
+/// Value of a `bind:group` binding, shared between a group of radio buttons or checkboxes
+pub trait BindGroup {
+    /// Returns `true` if the input with the specified `value` must be checked
+    fn contains (&self, value: &str) -> bool;
+    /// Updates the group after the input with the specified `value` has been checked or unchecked
+    fn toggle (&mut self, value: &str, checked: bool);
+}
+
+impl BindGroup for String {
+    #[inline]
+    fn contains (&self, value: &str) -> bool {
+        self == value
+    }
+
+    #[inline]
+    fn toggle (&mut self, value: &str, checked: bool) {
+        if checked {
+            *self = value.to_string()
+        }
+    }
+}
+
+impl BindGroup for Option<String> {
+    #[inline]
+    fn contains (&self, value: &str) -> bool {
+        self.as_deref() == Some(value)
+    }
+
+    #[inline]
+    fn toggle (&mut self, value: &str, checked: bool) {
+        if checked {
+            *self = Some(value.to_string())
+        }
+    }
+}
+
+impl BindGroup for Vec<String> {
+    #[inline]
+    fn contains (&self, value: &str) -> bool {
+        self.iter().any(|x| x == value)
+    }
+
+    #[inline]
+    fn toggle (&mut self, value: &str, checked: bool) {
+        match (self.iter().position(|x| x == value), checked) {
+            (None, true) => self.push(value.to_string()),
+            (Some(i), false) => { self.remove(i); },
+            _ => {}
+        }
+    }
+}
+
+impl<T: JsCast + AsRef<web_sys::Element>> Element<T> {
+    /// Binds the element's `value` property to `cell` in both directions.
+    /// The cell is updated on every `input` event, and the element whenever the cell changes.
+    /// Values that can't be parsed from the element's text are ignored.
+    pub fn bind_value<V> (&self, mut cell: V) -> Result<()>
+    where
+        V: 'static + MutableCell,
+        V::Value: FromString + RefProperty,
+        <V::Value as FromString>::Err: Display
+    {
+        let owner = Owner::new();
+        let updating = Rc::new(std::cell::Cell::new(false));
+        RefProperty::render(cell.get().deref(), self.raw(), "value")?;
+
+        let element = self.raw().clone();
+        let my_updating = updating.clone();
+        let my_owner = owner.clone();
+        cell.on_update(move |x| {
+            if my_updating.get() { return }
+            if let Err(e) = RefProperty::render(x, &element, "value") {
+                my_owner.throw(e)
+            }
+        });
+
+        let element = self.raw().clone();
+        return self.add_listener("input", move |_| {
+            let text = match get(&element, "value").map(|x| x.as_string()) {
+                Ok(Some(x)) => x,
+                Ok(None) => return,
+                Err(e) => return owner.throw(e)
+            };
+
+            let v = match <V::Value as FromString>::from_string(text) {
+                Ok(x) => x,
+                Err(_e) => {
+                    #[cfg(debug_assertions)]
+                    jseprintln!("invalid input value: {_e}");
+                    return
+                }
+            };
+
+            updating.set(true);
+            cell.mutate(|x| *x = v);
+            updating.set(false);
+        })
+    }
+
+    /// Binds the element's `checked` property to `cell` in both directions.
+    /// The cell is updated on every `change` event, and the element whenever the cell changes.
+    pub fn bind_checked<V: 'static + MutableCell<Value = bool>> (&self, mut cell: V) -> Result<()> {
+        let owner = Owner::new();
+        RefProperty::render(cell.get().deref(), self.raw(), "checked")?;
+
+        let element = self.raw().clone();
+        let my_owner = owner.clone();
+        cell.on_update(move |x| {
+            if let Err(e) = RefProperty::render(x, &element, "checked") {
+                my_owner.throw(e)
+            }
+        });
+
+        let element = self.raw().clone();
+        return self.add_listener("change", move |_| {
+            match get(&element, "checked") {
+                Ok(checked) => cell.set(checked.is_truthy()),
+                Err(e) => owner.throw(e)
+            }
+        })
+    }
+
+    /// Binds the element, a radio button or checkbox, to the group value `cell` in both directions.
+    /// The element is checked whenever the group contains it's `value`,
+    /// and the group is updated on every `change` event.
+    pub fn bind_group<V> (&self, mut cell: V) -> Result<()> where V: 'static + MutableCell, V::Value: BindGroup {
+        let owner = Owner::new();
+        let checked = |element: &web_sys::Element, group: &V::Value| -> Result<()> {
+            let value = get(element, "value")?.as_string().unwrap_or_default();
+            RefProperty::render(&group.contains(&value), element, "checked")
+        };
+        checked(self.raw(), cell.get().deref())?;
+
+        let element = self.raw().clone();
+        let my_owner = owner.clone();
+        cell.on_update(move |x| {
+            if let Err(e) = checked(&element, x) {
+                my_owner.throw(e)
+            }
+        });
+
+        let element = self.raw().clone();
+        return self.add_listener("change", move |_| {
+            let (value, checked) = match get(&element, "value").and_then(|value| Ok((value, get(&element, "checked")?))) {
+                Ok((value, checked)) => (value.as_string().unwrap_or_default(), checked.is_truthy()),
+                Err(e) => return owner.throw(e)
+            };
+
+            cell.mutate(|x| x.toggle(&value, checked))
+        })
+    }
+}
+
+#[inline]
+fn get (element: &web_sys::Element, name: &str) -> Result<JsValue> {
+    js_sys::Reflect::get(element, &JsValue::from_str(name))
+}
@@ -1,5 +1,5 @@
-use std::{hint::unreachable_unchecked, ops::Deref, rc::Rc, cell::{UnsafeCell, RefCell, Ref}, fmt::Debug};
-use crate::{Result, component::{RefComponent, Node}, attr::RefAttribute, jseprintln};
+use std::{collections::{HashSet, HashMap, hash_map::Entry}, hint::unreachable_unchecked, ops::Deref, rc::Rc, cell::{UnsafeCell, RefCell, Ref}, fmt::Debug};
+use crate::{Result, component::{RefComponent, Node, Element}, attr::{RefAttribute, RefAttributes, AttrNode}, prop::RefProperty, style::{RefClass, RefStyle}, region::Region, owner::Owner};
 
 /// An object that can be treated like a cell.
 /// Cells are objects that notify other parts of the code when their underlying value is mutated.
@@ -85,37 +85,145 @@ impl<T: RefMutableCell> MutableCell for T {
 impl<T: CellLike> RefComponent for T where <T as CellLike>::Value: RefComponent {
     fn render (&self) -> Result<Node> {
         let s = self.get();
-        let prev = RefComponent::render(s.deref())?;
-        let mut my_prev = prev.0.clone();
-
-        self.on_update(move |x| match RefComponent::render(x) {
-            Ok(x) => match my_prev.parent_node() {
-                Some(parent) => match parent.replace_child(&x.0, &my_prev) {
-                    Ok(_) => my_prev = x.0,
-                    Err(e) => wasm_bindgen::throw_val(e)
-                },
-                None => {
-                    #[cfg(debug_assertions)]
-                    jseprintln!("previous node doesn't have a parent")
-                }
-            },
-            Err(e) => wasm_bindgen::throw_val(e)
+        let owner = Owner::new();
+        let region = Region::new(owner.run(|| RefComponent::render(s.deref()))?)?;
+        let my_region = region.clone();
+
+        self.on_update(move |x| {
+            owner.cleanup();
+            match owner.run(|| RefComponent::render(x)).and_then(|x| my_region.replace(x)) {
+                Ok(_) => {},
+                Err(e) => owner.throw(e)
+            }
         });
 
-        return Ok(prev)
+        return region.take()
     }
 }
 
 impl<T: CellLike> RefAttribute for T where T::Value: RefAttribute {
     #[inline]
-    fn render (&self, attr: &web_sys::Attr) -> Result<()> {
+    fn render (&self, attr: &AttrNode) -> Result<()> {
         let s = self.get();
         s.render(attr)?;
 
         let attr = attr.clone();
+        let owner = Owner::new();
         self.on_update(move |x| match x.render(&attr) {
             Ok(_) => {},
-            Err(e) => wasm_bindgen::throw_val(e)
+            Err(e) => owner.throw(e)
+        });
+
+        Ok(())
+    }
+
+    #[inline]
+    fn update (&self, attr: &AttrNode) -> Result<()> {
+        self.get().update(attr)
+    }
+}
+
+impl<T: CellLike> RefAttributes for T where T::Value: RefAttributes {
+    #[inline]
+    fn for_each (&self, f: &mut dyn FnMut(&str, &dyn RefAttribute) -> Result<()>) -> Result<()> {
+        self.get().for_each(f)
+    }
+
+    /// Renders the current attributes, and keeps the element in sync with the cell:
+    /// every name keeps the same attribute node across updates, which is removed once the name stops being part of the collection.
+    /// Values are rendered as they are when the cell is updated, so cells inside the collection aren't tracked.
+    fn render (&self, element: &Element) -> Result<()> {
+        let mut nodes = HashMap::new();
+        update_attributes(self.get().deref(), element, &mut nodes)?;
+
+        let element = element.clone();
+        let owner = Owner::new();
+        self.on_update(move |x| match update_attributes(x, &element, &mut nodes) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
+        });
+
+        Ok(())
+    }
+}
+
+/// Renders the current value of every attribute of `attrs`, reusing the node of the names that were already rendered,
+/// and removing the nodes whose names aren't part of `attrs` anymore
+fn update_attributes<T: ?Sized + RefAttributes> (attrs: &T, element: &Element, nodes: &mut HashMap<String, AttrNode>) -> Result<()> {
+    let mut current = HashSet::with_capacity(nodes.len());
+    attrs.for_each(&mut |name, value| {
+        let node = match nodes.entry(name.to_string()) {
+            Entry::Occupied(x) => x.into_mut(),
+            Entry::Vacant(x) => x.insert(AttrNode::new(element.raw().clone(), name)?)
+        };
+
+        current.insert(name.to_string());
+        value.update(node)
+    })?;
+
+    let mut result = Ok(());
+    nodes.retain(|name, node| {
+        if current.contains(name) {
+            return true
+        }
+
+        if result.is_ok() {
+            result = node.remove()
+        }
+        return false
+    });
+
+    return result
+}
+
+impl<T: CellLike> RefProperty for T where T::Value: RefProperty {
+    #[inline]
+    fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+        let s = self.get();
+        s.render(element, name)?;
+
+        let element = element.clone();
+        let name = name.to_string();
+        let owner = Owner::new();
+        self.on_update(move |x| match x.render(&element, &name) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
+        });
+
+        Ok(())
+    }
+}
+
+impl<T: CellLike> RefClass for T where T::Value: RefClass {
+    #[inline]
+    fn render (&self, classes: &web_sys::DomTokenList, name: &str) -> Result<()> {
+        let s = self.get();
+        s.render(classes, name)?;
+
+        let classes = classes.clone();
+        let name = name.to_string();
+        let owner = Owner::new();
+        self.on_update(move |x| match x.render(&classes, &name) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
+        });
+
+        Ok(())
+    }
+}
+
+impl<T: CellLike> RefStyle for T where T::Value: RefStyle {
+    #[inline]
+    fn render (&self, style: &web_sys::CssStyleDeclaration, name: &str) -> Result<()> {
+        let s = self.get();
+        s.render(style, name)?;
+
+        let style = style.clone();
+        let name = name.to_string();
+        let owner = Owner::new();
+        self.on_update(move |x| match x.render(&style, &name) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
         });
 
         Ok(())
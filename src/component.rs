@@ -1,8 +1,8 @@
-use std::{ops::Deref};
+use std::{ops::Deref, rc::Rc, sync::Arc, borrow::Cow, cell::RefCell};
 use js_sys::Function;
 use wasm_bindgen::{prelude::Closure, JsCast};
 use web_sys::{EventTarget, AddEventListenerOptions};
-use crate::{Result, dynui::Attribute, CONTEXT};
+use crate::{Result, dynui::Attribute, attr::{AttrNode, Attributes}, prop::Property, style::{Class, Style}};
 
 #[derive(Debug)]
 #[repr(transparent)]
@@ -49,6 +49,37 @@ impl Node {
         let listener = Closure::once_into_js(f).unchecked_into::<Function>();
         return self.0.add_event_listener_with_callback_and_add_event_listener_options(event, &listener, options.once(true));
     }
+
+    // Currently leaks
+    pub fn add_listener_with_options<F: 'static + FnMut(web_sys::Event)> (&self, event: &str, options: ListenerOptions, mut f: F) -> Result<()> {
+        let ListenerOptions { prevent_default, stop_propagation, once, passive, capture } = options;
+        let listener = Closure::new(move |e: web_sys::Event| {
+            if prevent_default { e.prevent_default() }
+            if stop_propagation { e.stop_propagation() }
+            f(e)
+        }).into_js_value().unchecked_into::<Function>();
+
+        let options = AddEventListenerOptions::new();
+        options.set_once(once);
+        options.set_passive(passive);
+        options.set_capture(capture);
+        return self.0.add_event_listener_with_callback_and_add_event_listener_options(event, &listener, &options);
+    }
+}
+
+/// Options of an event listener, matching the modifiers of `on:` directives
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListenerOptions {
+    /// Calls [`prevent_default`](web_sys::Event::prevent_default) before executing the listener
+    pub prevent_default: bool,
+    /// Calls [`stop_propagation`](web_sys::Event::stop_propagation) before executing the listener
+    pub stop_propagation: bool,
+    /// Removes the listener after it's first execution
+    pub once: bool,
+    /// Promises the browser that the listener won't call [`prevent_default`](web_sys::Event::prevent_default)
+    pub passive: bool,
+    /// Executes the listener during the capture phase
+    pub capture: bool
 }
 
 impl Deref for Node {
@@ -60,25 +91,148 @@ impl Deref for Node {
     }
 }
 
-#[derive(Debug)]
+/// Element created by `html!` (or [`create_element`](crate::create_element)), typed by it's `web_sys` interface.
+/// The methods of the interface are available directly (i.e. `Element<HtmlInputElement>::value`),
+/// and the untyped element through [`untyped`](Element::untyped), [`into_untyped`](Element::into_untyped) or [`From`].
+/// Untyped elements dereference to their [`Node`].
+#[derive(Debug, Clone)]
 #[repr(transparent)]
-pub struct Element (pub(crate) web_sys::Element);
+pub struct Element<T = web_sys::Element> (pub(crate) T);
 
 impl Element {
     #[inline]
     pub unsafe fn new<T: Into<web_sys::Element>> (v: T) -> Self {
         Self(v.into())
     }
+}
+
+impl<T: JsCast + AsRef<web_sys::Element>> Element<T> {
+    #[inline]
+    pub(crate) fn raw (&self) -> &web_sys::Element {
+        AsRef::<web_sys::Element>::as_ref(&self.0)
+    }
+
+    /// Returns the element without it's interface
+    #[inline]
+    pub fn untyped (&self) -> &Element {
+        unsafe {
+            &*(self.raw() as *const web_sys::Element as *const Element)
+        }
+    }
 
+    /// Converts the element into it's untyped form
     #[inline]
-    pub fn set_attribute<T: Attribute> (&self, name: &str, value: T) -> Result<Option<web_sys::Attr>> {
-        let attr: web_sys::Attr = CONTEXT.with(|ctx| ctx.document.create_attribute(name))?;
+    pub fn into_untyped (self) -> Element {
+        Element(self.0.unchecked_into())
+    }
+
+    /// Returns the element as a [`Node`]
+    #[inline]
+    pub fn as_node (&self) -> &Node {
+        unsafe {
+            &*(self.raw() as &web_sys::Node as *const web_sys::Node as *const Node)
+        }
+    }
+
+    /// Casts the element into the interface `U`, without checking that it implements it
+    #[inline]
+    pub fn unchecked_into<U: JsCast> (self) -> Element<U> {
+        Element(self.0.unchecked_into())
+    }
+
+    /// Casts the element into the interface `U`, returning it back if it doesn't implement it
+    #[inline]
+    pub fn dyn_into<U: JsCast> (self) -> core::result::Result<Element<U>, Self> {
+        self.0.dyn_into().map(Element).map_err(Element)
+    }
+
+    /// Sets the attribute `name` to `value`.
+    /// Depending on `value`, the attribute may end up not being present on the element (i.e. `false` or `None`)
+    #[inline]
+    pub fn set_attribute<V: Attribute> (&self, name: &str, value: V) -> Result<AttrNode> {
+        let attr = AttrNode::new(self.raw().clone(), name)?;
         value.render(&attr)?;
-        return self.0.set_attribute_node(&attr)
+        return Ok(attr)
+    }
+
+    /// Sets the attribute with the qualified `name`, inside the specified `namespace`, to `value` (i.e. `xlink:href`)
+    #[inline]
+    pub fn set_attribute_ns<V: Attribute> (&self, namespace: Option<&str>, name: &str, value: V) -> Result<AttrNode> {
+        let attr = AttrNode::new_ns(self.raw().clone(), namespace, name)?;
+        value.render(&attr)?;
+        return Ok(attr)
+    }
+
+    /// Sets the DOM property `name` to `value`.
+    /// Unlike attributes, properties reflect the live state of the element (i.e. an input's current `value`)
+    #[inline]
+    pub fn set_property<V: Property> (&self, name: &str, value: V) -> Result<()> {
+        value.render(self.raw(), name)
+    }
+
+    /// Returns the element's list of classes
+    #[inline]
+    pub fn class_list (&self) -> web_sys::DomTokenList {
+        self.raw().class_list()
+    }
+
+    /// Adds the class `name` to the element while `value` is `true`, and removes it otherwise
+    #[inline]
+    pub fn set_class<V: Class> (&self, name: &str, value: V) -> Result<()> {
+        value.render(&self.class_list(), name)
+    }
+
+    /// Returns the element's inline style declaration
+    #[inline]
+    pub fn style (&self) -> Result<web_sys::CssStyleDeclaration> {
+        js_sys::Reflect::get(self.raw(), &wasm_bindgen::JsValue::from_str("style")).map(JsCast::unchecked_into)
+    }
+
+    /// Sets the inline CSS property `name` to `value`.
+    /// If `value` is `None`, the property is removed
+    #[inline]
+    pub fn set_style<V: Style> (&self, name: &str, value: V) -> Result<()> {
+        value.render(&self.style()?, name)
+    }
+
+    /// Sets every attribute of `attrs`
+    #[inline]
+    pub fn set_attributes<V: Attributes> (&self, attrs: V) -> Result<()> {
+        attrs.render(self.untyped())
+    }
+
+    /// Removes the attribute `name` from the element
+    #[inline]
+    pub fn remove_attribute (&self, name: &str) -> Result<()> {
+        self.raw().remove_attribute(name)
+    }
+
+    /// See [`Node::append_child`]
+    #[inline]
+    pub fn append_child<N: Into<Node>> (&self, child: N) -> Result<Node> {
+        self.as_node().append_child(child)
+    }
+
+    /// See [`Node::add_listener`]
+    #[inline]
+    pub fn add_listener<F: 'static + FnMut(web_sys::Event)> (&self, event: &str, f: F) -> Result<()> {
+        self.as_node().add_listener(event, f)
+    }
+
+    /// See [`Node::add_once_listener`]
+    #[inline]
+    pub fn add_once_listener<F: 'static + FnOnce(web_sys::Event)> (&self, event: &str, f: F) -> Result<()> {
+        self.as_node().add_once_listener(event, f)
+    }
+
+    /// See [`Node::add_listener_with_options`]
+    #[inline]
+    pub fn add_listener_with_options<F: 'static + FnMut(web_sys::Event)> (&self, event: &str, options: ListenerOptions, f: F) -> Result<()> {
+        self.as_node().add_listener_with_options(event, options, f)
     }
 }
 
-impl Into<Node> for Element {
+impl<T: Into<web_sys::Node>> Into<Node> for Element<T> {
     #[inline]
     fn into(self) -> Node {
         Node(self.0.into())
@@ -90,9 +244,120 @@ impl Deref for Element {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        unsafe {
-            &*(&self.0 as &web_sys::Node as *const web_sys::Node as *const Node)
+        self.as_node()
+    }
+}
+
+macro_rules! impl_interface {
+    ($($t:ident),+) => {
+        $(
+            impl Deref for Element<web_sys::$t> {
+                type Target = web_sys::$t;
+
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl From<Element<web_sys::$t>> for Element {
+                #[inline]
+                fn from(value: Element<web_sys::$t>) -> Self {
+                    value.into_untyped()
+                }
+            }
+        )+
+    };
+}
+
+impl_interface! {
+    HtmlAnchorElement, HtmlAreaElement, HtmlAudioElement, HtmlBaseElement, HtmlBodyElement, HtmlBrElement,
+    HtmlButtonElement, HtmlCanvasElement, HtmlDListElement, HtmlDataElement, HtmlDataListElement, HtmlDetailsElement,
+    HtmlDialogElement, HtmlDivElement, HtmlElement, HtmlEmbedElement, HtmlFieldSetElement, HtmlFormElement,
+    HtmlHeadElement, HtmlHeadingElement, HtmlHrElement, HtmlHtmlElement, HtmlIFrameElement, HtmlImageElement,
+    HtmlInputElement, HtmlLabelElement, HtmlLegendElement, HtmlLiElement, HtmlLinkElement, HtmlMapElement,
+    HtmlMenuElement, HtmlMetaElement, HtmlMeterElement, HtmlModElement, HtmlOListElement, HtmlObjectElement,
+    HtmlOptGroupElement, HtmlOptionElement, HtmlOutputElement, HtmlParagraphElement, HtmlPictureElement,
+    HtmlPreElement, HtmlProgressElement, HtmlQuoteElement, HtmlScriptElement, HtmlSelectElement, HtmlSlotElement,
+    HtmlSourceElement, HtmlSpanElement, HtmlStyleElement, HtmlTableCaptionElement, HtmlTableCellElement,
+    HtmlTableColElement, HtmlTableElement, HtmlTableRowElement, HtmlTableSectionElement, HtmlTemplateElement,
+    HtmlTextAreaElement, HtmlTimeElement, HtmlTitleElement, HtmlTrackElement, HtmlUListElement, HtmlVideoElement,
+    MathMlElement, SvgAnimateElement, SvgAnimateMotionElement, SvgAnimateTransformElement, SvgCircleElement,
+    SvgClipPathElement, SvgDefsElement, SvgDescElement, SvgElement, SvgEllipseElement, SvgFilterElement,
+    SvgForeignObjectElement, SvgImageElement, SvgLineElement, SvgLinearGradientElement, SvgMarkerElement,
+    SvgMaskElement, SvgMetadataElement, SvgPathElement, SvgPatternElement, SvgPolygonElement, SvgPolylineElement,
+    SvgRadialGradientElement, SvgRectElement, SvgScriptElement, SvgSetElement, SvgStopElement, SvgStyleElement,
+    SvgSwitchElement, SvgSymbolElement, SvgTextElement, SvgTextPathElement, SvgTitleElement, SvgUseElement,
+    SvgViewElement, SvgaElement, SvgfeBlendElement, SvgfeColorMatrixElement, SvgfeComponentTransferElement,
+    SvgfeCompositeElement, SvgfeConvolveMatrixElement, SvgfeDiffuseLightingElement, SvgfeDisplacementMapElement,
+    SvgfeDistantLightElement, SvgfeDropShadowElement, SvgfeFloodElement, SvgfeFuncAElement, SvgfeFuncBElement,
+    SvgfeFuncGElement, SvgfeFuncRElement, SvgfeGaussianBlurElement, SvgfeImageElement, SvgfeMergeElement,
+    SvgfeMergeNodeElement, SvgfeMorphologyElement, SvgfeOffsetElement, SvgfePointLightElement,
+    SvgfeSpecularLightingElement, SvgfeSpotLightElement, SvgfeTileElement, SvgfeTurbulenceElement, SvggElement,
+    SvgmPathElement, SvgsvgElement, SvgtSpanElement
+}
+
+/// Handle to an element created inside `html!`, filled by it's `ref={node_ref}` attribute once the element is created.
+/// `T` may be the element's own interface or any of it's parents (i.e. `HtmlElement` for an `<input/>`)
+pub struct NodeRef<T = web_sys::Element> (Rc<NodeRefState<T>>);
+
+struct NodeRefState<T> {
+    value: RefCell<Option<T>>,
+    listeners: RefCell<Vec<Box<dyn FnMut(&T)>>>
+}
+
+impl<T: 'static + Clone> NodeRef<T> {
+    /// Creates a new empty reference
+    #[inline]
+    pub fn new () -> Self {
+        return Self(Rc::new(NodeRefState {
+            value: RefCell::new(None),
+            listeners: RefCell::new(Vec::new())
+        }))
+    }
+
+    /// Returns the referenced element, if it has been created
+    #[inline]
+    pub fn get (&self) -> Option<T> {
+        self.0.value.borrow().clone()
+    }
+
+    /// Points the reference to `element`, notifying it's listeners
+    pub fn set<U: JsCast + AsRef<T>> (&self, element: &Element<U>) {
+        let element = AsRef::<T>::as_ref(&element.0).clone();
+        *self.0.value.borrow_mut() = Some(element.clone());
+
+        let mut listeners = core::mem::take(&mut *self.0.listeners.borrow_mut());
+        for f in listeners.iter_mut() {
+            f(&element)
         }
+
+        let mut current = self.0.listeners.borrow_mut();
+        listeners.append(&mut current);
+        *current = listeners;
+    }
+
+    /// Executes `f` every time the reference is set.
+    /// If the reference has already been set, `f` is also executed right away with the current element
+    pub fn on_set<F: 'static + FnMut(&T)> (&self, mut f: F) {
+        if let Some(ref element) = self.get() {
+            f(element)
+        }
+        self.0.listeners.borrow_mut().push(Box::new(f))
+    }
+}
+
+impl<T: 'static + Clone> Default for NodeRef<T> {
+    #[inline]
+    fn default () -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for NodeRef<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
     }
 }
 
@@ -166,6 +431,249 @@ impl RefComponent for Box<str> {
     }
 }
 
+impl RefComponent for Rc<str> {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        RefComponent::render(&web_sys::Text::new_with_data(self)?)
+    }
+}
+
+impl RefComponent for Arc<str> {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        RefComponent::render(&web_sys::Text::new_with_data(self)?)
+    }
+}
+
+impl RefComponent for Cow<'_, str> {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        RefComponent::render(&web_sys::Text::new_with_data(self)?)
+    }
+}
+
+/// Components that can be used as tags inside `html!`, which builds them through their props builder.
+/// It's implemented by `#[component]` functions and by structs deriving [`Props`](macros::Props) or [`Component`](macros::Component).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a tag inside `html!`",
+    label = "`{Self}` has no props builder",
+    note = "derive `Props` (or `Component`) for it, or declare it with `#[component]`",
+    note = "otherwise, build it yourself and render it as an expression (i.e. `{{{Self}::new(..)}}`)"
+)]
+pub trait Props {
+    type Builder;
+
+    /// Returns a builder for the component's props
+    fn builder () -> Self::Builder;
+}
+
+/// Resolves the `builder` of the tags inside `html!` that don't have one, so that they're reported as not implementing [`Props`]
+#[doc(hidden)]
+pub trait PropsFallback {
+    #[inline]
+    fn builder () -> <Self as Props>::Builder where Self: Props {
+        <Self as Props>::builder()
+    }
+}
+
+impl<T: ?Sized> PropsFallback for T {}
+
+/// State of a component builder's required prop once it has been set
+#[doc(hidden)]
+pub struct Set;
+
+/// Implemented by the states of a component builder's required props that have been set
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "missing required prop `{Self}`",
+    label = "the `{Self}` prop must be set before building the component"
+)]
+pub trait IsSet {}
+impl IsSet for Set {}
+
+/// Markup nested inside a component's tag in `html!`, or inside one of it's named slots.
+/// Children are rendered lazily, every time they're rendered, so a component may render them as many times as it needs (or not at all).
+///
+/// The markup is moved into the closure that renders it, so it can't move the values it captures out of it
+/// (i.e. children nested inside other children that render a `String` by value). Components that render their children once
+/// may take [`ChildrenOnce`] instead, which accepts such markup.
+#[derive(Clone)]
+pub struct Children (Rc<dyn Fn() -> Result<Node>>);
+
+impl Children {
+    #[inline]
+    pub fn new<F: 'static + Fn() -> Result<Node>> (f: F) -> Self {
+        return Self(Rc::new(f))
+    }
+
+    /// Returns children that render nothing
+    #[inline]
+    pub fn empty () -> Self {
+        return Self::new(|| Component::render(()))
+    }
+}
+
+impl Default for Children {
+    #[inline]
+    fn default () -> Self {
+        Self::empty()
+    }
+}
+
+impl RefComponent for Children {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        (self.0)()
+    }
+}
+
+/// Markup nested inside a component's tag in `html!` that's rendered at most once, so it may move the values it captures.
+/// See [`Children`]
+pub struct ChildrenOnce (Box<dyn FnOnce() -> Result<Node>>);
+
+impl ChildrenOnce {
+    #[inline]
+    pub fn new<F: 'static + FnOnce() -> Result<Node>> (f: F) -> Self {
+        return Self(Box::new(f))
+    }
+
+    /// Returns children that render nothing
+    #[inline]
+    pub fn empty () -> Self {
+        return Self::new(|| Component::render(()))
+    }
+}
+
+impl Default for ChildrenOnce {
+    #[inline]
+    fn default () -> Self {
+        Self::empty()
+    }
+}
+
+impl Component for ChildrenOnce {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        (self.0)()
+    }
+}
+
+/// Markup nested inside a component's tag in `html!`, before it's turned into the [`Children`] or [`ChildrenOnce`] the component takes
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct Lazy<F> (pub F);
+
+impl<F: FnOnce() -> Result<Node>> Component for Lazy<F> {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        (self.0)()
+    }
+}
+
+/// Lets nested markup be passed to props that take a closure, like [`ErrorBoundary`](crate::lib::error::ErrorBoundary)'s children
+impl<F: FnOnce() -> R, R> FnOnce<()> for Lazy<F> {
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once (self, _: ()) -> R {
+        (self.0)()
+    }
+}
+
+impl<F: FnMut() -> R, R> FnMut<()> for Lazy<F> {
+    #[inline]
+    extern "rust-call" fn call_mut (&mut self, _: ()) -> R {
+        (self.0)()
+    }
+}
+
+impl<F: Fn() -> R, R> Fn<()> for Lazy<F> {
+    #[inline]
+    extern "rust-call" fn call (&self, _: ()) -> R {
+        (self.0)()
+    }
+}
+
+impl<F: 'static + Fn() -> Result<Node>> From<Lazy<F>> for Children {
+    #[inline]
+    fn from (value: Lazy<F>) -> Self {
+        Self::new(value.0)
+    }
+}
+
+impl<F: 'static + FnOnce() -> Result<Node>> From<Lazy<F>> for ChildrenOnce {
+    #[inline]
+    fn from (value: Lazy<F>) -> Self {
+        Self::new(value.0)
+    }
+}
+
+macro_rules! impl_owned {
+    ($($t:ty),+) => {
+        $(
+            impl Component for $t {
+                #[inline]
+                fn render (self) -> Result<Node> {
+                    RefComponent::render(&self)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_display {
+    ($($t:ty),+) => {
+        $(
+            impl RefComponent for $t {
+                #[inline]
+                fn render (&self) -> Result<Node> {
+                    RefComponent::render(&web_sys::Text::new_with_data(&self.to_string())?)
+                }
+            }
+        )+
+
+        impl_owned! { $($t),+ }
+    };
+}
+
+impl_owned! { String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str>, Children }
+impl_display! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, bool, char
+}
+
+impl<T: RefComponent> RefComponent for Option<T> {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        match self {
+            Some(x) => RefComponent::render(x),
+            None => Component::render(())
+        }
+    }
+}
+
+impl<T: RefComponent> RefComponent for [T] {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        fragment(self.iter().map(RefComponent::render))
+    }
+}
+
+impl<T: RefComponent, const N: usize> RefComponent for [T; N] {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        RefComponent::render(self.as_slice())
+    }
+}
+
+impl<T: RefComponent> RefComponent for Vec<T> {
+    #[inline]
+    fn render (&self) -> Result<Node> {
+        RefComponent::render(self.as_slice())
+    }
+}
+
 /// A component that can be rendered by ownership.
 /// A `Component` may only be appended to a parent once.
 pub trait Component {
@@ -186,7 +694,7 @@ impl Component for Node {
     }
 }
 
-impl Component for Element {
+impl<T: Into<web_sys::Node>> Component for Element<T> {
     #[inline(always)]
     fn render (self) -> Result<Node> {
         Ok(self.into())
@@ -200,4 +708,77 @@ impl Component for web_sys::Text {
             return Ok(Node::new(self))
         }
     }
+}
+
+impl<T: Component> Component for Option<T> {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        match self {
+            Some(x) => x.render(),
+            None => Component::render(())
+        }
+    }
+}
+
+impl<T: Component> Component for Vec<T> {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        fragment(self)
+    }
+}
+
+impl<T: Component, const N: usize> Component for [T; N] {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        fragment(self)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: RefComponent),*> RefComponent for ($($t,)*) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn render (&self) -> Result<Node> {
+                let ($($t,)*) = self;
+                let fragment = web_sys::DocumentFragment::new()?;
+                $(fragment.append_child(&RefComponent::render($t)?.0)?;)*
+                return unsafe { Ok(Node::new(fragment)) }
+            }
+        }
+
+        impl<$($t: Component),*> Component for ($($t,)*) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn render (self) -> Result<Node> {
+                let ($($t,)*) = self;
+                let fragment = web_sys::DocumentFragment::new()?;
+                $(fragment.append_child(&Component::render($t)?.0)?;)*
+                return unsafe { Ok(Node::new(fragment)) }
+            }
+        }
+    };
+}
+
+impl_tuple! {}
+impl_tuple! { A }
+impl_tuple! { A, B }
+impl_tuple! { A, B, C }
+impl_tuple! { A, B, C, D }
+impl_tuple! { A, B, C, D, E }
+impl_tuple! { A, B, C, D, E, F }
+impl_tuple! { A, B, C, D, E, F, G }
+impl_tuple! { A, B, C, D, E, F, G, H }
+impl_tuple! { A, B, C, D, E, F, G, H, I }
+impl_tuple! { A, B, C, D, E, F, G, H, I, J }
+impl_tuple! { A, B, C, D, E, F, G, H, I, J, K }
+impl_tuple! { A, B, C, D, E, F, G, H, I, J, K, L }
+
+/// Renders every component of `iter` into a single [`DocumentFragment`](web_sys::DocumentFragment)
+pub fn fragment<I: IntoIterator> (iter: I) -> Result<Node> where I::Item: Component {
+    let fragment = web_sys::DocumentFragment::new()?;
+    for item in iter {
+        fragment.append_child(&item.render()?.0)?;
+    }
+    return unsafe { Ok(Node::new(fragment)) }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 #![feature(min_specialization, is_some_and)]
 #![feature(drain_filter)]
 #![feature(new_uninit, const_alloc_layout, ptr_metadata, alloc_layout_extra)]
+#![feature(fn_traits, unboxed_closures)]
 
 macro_rules! flat_mod {
     ($($i:ident),+) => {
@@ -42,6 +43,12 @@ pub mod lib;
 pub mod component;
 pub mod cell;
 pub mod attr;
+pub mod prop;
+pub mod style;
+pub mod bind;
+pub mod region;
+pub mod owner;
+pub mod text;
 
 pub type Result<T> = ::core::result::Result<T, wasm_bindgen::JsValue>;
 
@@ -95,6 +102,12 @@ impl Context {
         return self.document.create_element(name).map(Element)
     }
 
+    /// Creates a new [`Element`] with the specified qualified `name`, inside the specified `namespace` (i.e. SVG's)
+    #[inline]
+    pub fn create_element_ns (&self, namespace: Option<&str>, name: &str) -> Result<Element> {
+        return self.document.create_element_ns(namespace, name).map(Element)
+    }
+
     /// Binds `f` to be executed after the specified time
     #[inline]
     pub fn set_timeout<F: 'static + FnOnce()> (&self, time: Duration, f: F) -> Result<i32> {
@@ -144,6 +157,12 @@ pub fn create_element (name: &str) -> Result<Element> {
     CONTEXT.with(|ctx| ctx.create_element(name))
 }
 
+/// Creates a new [`Element`] with the specified qualified `name`, inside the specified `namespace` (i.e. SVG's)
+#[inline]
+pub fn create_element_ns (namespace: Option<&str>, name: &str) -> Result<Element> {
+    CONTEXT.with(|ctx| ctx.create_element_ns(namespace, name))
+}
+
 /// Binds `f` to be executed after the specified time
 #[inline]
 pub fn set_timeout<F: 'static + FnOnce()> (time: Duration, f: F) -> Result<i32> {
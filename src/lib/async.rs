@@ -1,34 +1,263 @@
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::rc::Rc;
 use std::time::Duration;
 use js_sys::{Promise, Function};
-use macros::{component};
+use macros::{component, Props};
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::JsFuture;
+use crate::cell::{CellLike, SharedCell, RefMutableCell};
 use crate::component::{Component, Node};
-use crate::{dynui, Result, CONTEXT, jseprintln};
+use crate::lib::control::Branch;
+use crate::owner::{Owner, use_context};
+use crate::region::Region;
+use crate::{dynui, Result, CONTEXT};
 
 #[component]
 pub fn Future<Fut: 'static + std::future::Future, P: Component> (fut: Fut, placeholder: P) -> Result<Node> where Fut::Output: Component {
-    let element = placeholder.render()?;
-    let my_element = element.0.clone();
+    let scope = Owner::new();
+    let owner = scope.child();
+    let pending = Pending::new();
+    let region = Region::new(owner.run(|| placeholder.render())?)?;
+    let my_region = region.clone();
 
     wasm_bindgen_futures::spawn_local(async move {
-        match fut.await.render() {
-            Ok(x) => match my_element.parent_node() {
-                Some(parent) => match parent.replace_child(&x.0, &my_element) {
-                    Ok(_) => {},
-                    Err(e) => wasm_bindgen::throw_val(e)
-                },
-                None => {
-                    #[cfg(debug_assertions)]
-                    jseprintln!("previous node doesn't have a parent")
-                }
-            },
+        let output = Owned { owner: scope, fut }.await;
+        owner.cleanup();
 
-            Err(e) => wasm_bindgen::throw_val(e)
+        match owner.run(|| output.render()).and_then(|x| my_region.replace(x)) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
         }
+
+        drop(pending);
     });
 
-    return Ok(element)
+    return region.take()
+}
+
+/// Future that's always polled with `owner` as the current owner, so it can access it's context
+struct Owned<F> {
+    owner: Owner,
+    fut: F
+}
+
+impl<F: std::future::Future> std::future::Future for Owned<F> {
+    type Output = F::Output;
+
+    #[inline]
+    fn poll (self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let fut = unsafe { Pin::new_unchecked(&mut this.fut) };
+        this.owner.run(|| fut.poll(cx))
+    }
+}
+
+/// Cell containing the result of an asynchronous computation, which can be fetched again at any time.
+/// Resources created under a [`Suspense`] keep it in it's fallback state while they're loading.
+pub struct Resource<T> {
+    value: SharedCell<Option<T>>,
+    fetch: Rc<dyn Fn() -> Pin<Box<dyn std::future::Future<Output = T>>>>,
+    version: Rc<std::cell::Cell<usize>>,
+    loading: SharedCell<bool>,
+    suspense: Option<SuspenseContext>
+}
+
+impl<T: 'static> Resource<T> {
+    /// Creates a new resource, and starts fetching it's value
+    pub fn new<F: 'static + Fn() -> Fut, Fut: 'static + std::future::Future<Output = T>> (fetch: F) -> Self {
+        let this = Self {
+            value: SharedCell::new(None),
+            fetch: Rc::new(move || Box::pin(fetch())),
+            version: Default::default(),
+            loading: SharedCell::new(false),
+            suspense: use_context()
+        };
+
+        this.refetch();
+        return this
+    }
+
+    /// Fetches the resource's value again.
+    /// The current value is kept until the new one is ready, and results of previous fetches that haven't finished yet are discarded.
+    pub fn refetch (&self) {
+        let version = self.version.get().wrapping_add(1);
+        self.version.set(version);
+
+        let pending = self.suspense.as_ref().map(SuspenseContext::pending);
+        let fut = (self.fetch)();
+        RefMutableCell::set(&self.loading, true);
+
+        let my_version = self.version.clone();
+        let value = self.value.clone();
+        let loading = self.loading.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let output = fut.await;
+            if my_version.get() == version {
+                RefMutableCell::set(&value, Some(output));
+                RefMutableCell::set(&loading, false);
+            }
+            drop(pending);
+        });
+    }
+
+    /// Returns a cell that's `true` while the resource is being fetched
+    #[inline]
+    pub fn loading (&self) -> &SharedCell<bool> {
+        &self.loading
+    }
+}
+
+impl<T: 'static> CellLike for Resource<T> {
+    type Value = Option<T>;
+    type Ref<'b> = <SharedCell<Option<T>> as CellLike>::Ref<'b>;
+
+    #[inline]
+    fn get (&self) -> Self::Ref<'_> {
+        return self.value.get();
+    }
+
+    #[inline]
+    fn on_update<F: 'static + FnMut(&Option<T>)> (&self, f: F) {
+        self.value.on_update(f)
+    }
+
+    #[inline]
+    fn on_update_once<F: 'static + FnOnce(&Option<T>)> (&self, f: F) {
+        self.value.on_update_once(f)
+    }
+}
+
+impl<T> Clone for Resource<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            fetch: self.fetch.clone(),
+            version: self.version.clone(),
+            loading: self.loading.clone(),
+            suspense: self.suspense.clone()
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct SuspenseContext (Rc<SuspenseState>);
+
+#[derive(Default)]
+struct SuspenseState {
+    pending: std::cell::Cell<usize>,
+    listener: RefCell<Option<Box<dyn FnMut(bool)>>>
+}
+
+impl SuspenseContext {
+    #[inline]
+    fn is_pending (&self) -> bool {
+        self.0.pending.get() > 0
+    }
+
+    fn pending (&self) -> Pending {
+        let pending = self.0.pending.get();
+        self.0.pending.set(pending + 1);
+        if pending == 0 {
+            self.notify(true)
+        }
+        return Pending(self.clone())
+    }
+
+    fn notify (&self, pending: bool) {
+        if let Ok(mut listener) = self.0.listener.try_borrow_mut() {
+            if let Some(ref mut f) = *listener {
+                f(pending)
+            }
+        }
+    }
+}
+
+/// Guard for a piece of asynchronous work the closest [`Suspense`] is waiting on.
+/// The work is considered done when the guard is dropped.
+pub struct Pending (SuspenseContext);
+
+impl Pending {
+    /// Registers pending work with the closest [`Suspense`], if any
+    #[inline]
+    pub fn new () -> Option<Self> {
+        use_context::<SuspenseContext>().map(|x| x.pending())
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        let pending = self.0.0.pending.get() - 1;
+        self.0.0.pending.set(pending);
+        if pending == 0 {
+            self.0.notify(false)
+        }
+    }
+}
+
+/// Shows a single `fallback` while any [`Future`], [`Resource`] or [`Pending`] work created by `children` is pending.
+/// `children` is rendered right away, but it's nodes are only shown once all of it's pending work is done.
+/// Whenever new work starts (i.e. a resource is refetched), the fallback is shown again,
+/// unless `transition` is `true`, in which case the previous content is kept on screen once it has been shown.
+#[derive(Props)]
+pub struct Suspense<C, F> {
+    pub children: C,
+    pub fallback: F,
+    #[prop(default)]
+    pub transition: bool
+}
+
+impl<C, F> Suspense<C, F> {
+    #[inline]
+    pub fn new (children: C, fallback: F, transition: bool) -> Self {
+        return Self { children, fallback, transition }
+    }
+}
+
+impl<C, R, F> Component for Suspense<C, F>
+where
+    C: FnOnce() -> R,
+    R: Component,
+    F: 'static + Component
+{
+    fn render (self) -> Result<Node> {
+        let Self { children, fallback, transition } = self;
+        let region = Region::empty()?;
+        let context = SuspenseContext::default();
+        let mut fallback = Branch::new(Owner::new(), fallback);
+
+        let owner = Owner::new();
+        owner.provide(context.clone());
+        let content = Region::new(owner.run(|| children().render())?)?;
+
+        let mut revealed = !context.is_pending();
+        region.replace(match revealed {
+            true => content.take()?,
+            false => fallback.mount()?
+        })?;
+
+        let my_region = region.clone();
+        *context.0.listener.borrow_mut() = Some(Box::new(move |pending| {
+            let result = match pending {
+                true if transition && revealed => return,
+                true => content.remove().and_then(|_| fallback.mount()),
+                false => {
+                    revealed = true;
+                    fallback.unmount().and_then(|_| content.take())
+                }
+            };
+
+            match result.and_then(|x| my_region.replace(x)) {
+                Ok(_) => {},
+                Err(e) => owner.throw(e)
+            }
+        }));
+
+        return region.take()
+    }
 }
 
 #[inline]
@@ -0,0 +1,241 @@
+use std::mem::{discriminant, Discriminant};
+use std::ops::Deref;
+use macros::{component, Props};
+use crate::cell::{CellLike, SharedCell, RefMutableCell};
+use crate::component::{Component, Node};
+use crate::owner::Owner;
+use crate::region::Region;
+use crate::{dynui, Result};
+
+/// A component that's rendered lazily, the first time it's mounted, and kept alive afterwards.
+/// Unmounting a branch doesn't dispose it: it's owner is only cleaned up along with the owner it was created under.
+pub(crate) struct Branch<C> {
+    component: Option<C>,
+    region: Option<Region>,
+    owner: Owner
+}
+
+impl<C: Component> Branch<C> {
+    #[inline]
+    pub fn new (owner: Owner, component: C) -> Self {
+        Self { component: Some(component), region: None, owner }
+    }
+
+    /// Returns the branch's nodes, rendering them if they haven't been rendered yet
+    pub fn mount (&mut self) -> Result<Node> {
+        if let Some(ref region) = self.region {
+            return region.take()
+        }
+
+        let component = match self.component.take() {
+            Some(x) => x,
+            None => return Err(wasm_bindgen::JsValue::from_str("branch component has already been consumed"))
+        };
+
+        let region = Region::new(self.owner.run(|| component.render())?)?;
+        return self.region.insert(region).take()
+    }
+
+    /// Detaches the branch's nodes from the DOM, keeping them (and their state) for a later mount
+    #[inline]
+    pub fn unmount (&mut self) -> Result<()> {
+        match self.region {
+            Some(ref region) => region.remove(),
+            None => Ok(())
+        }
+    }
+}
+
+/// Renders `children` while `when` is `true`, and `fallback` (nothing by default) otherwise.
+/// The shown branch keeps it's nodes (and their state) until the condition flips, at which point it's disposed
+/// and the other branch is rendered. Branches are rendered again from a clone every time they're shown,
+/// so nested markup is rebuilt from scratch, while an already built node is shown again as is.
+#[component]
+pub fn Show<W: CellLike<Value = bool>, C: 'static + Clone + Component, F: 'static + Clone + Component> (when: W, children: C, #[prop(default)] fallback: F) -> Result<Node> {
+    return show(&when, move || children.clone(), move || fallback.clone())
+}
+
+pub(crate) fn show<W, T, A, F, B> (when: &W, mut then: T, mut otherwise: F) -> Result<Node>
+where
+    W: ?Sized + CellLike<Value = bool>,
+    T: 'static + FnMut() -> A,
+    A: Component,
+    F: 'static + FnMut() -> B,
+    B: Component
+{
+    let owner = Owner::new();
+    let mut current = *when.get();
+    let region = Region::new(owner.run(|| match current {
+        true => then().render(),
+        false => otherwise().render()
+    })?)?;
+
+    let my_region = region.clone();
+    when.on_update(move |&x| {
+        if x == current { return }
+        current = x;
+        owner.cleanup();
+
+        let node = owner.run(|| match x {
+            true => then().render(),
+            false => otherwise().render()
+        });
+
+        match node.and_then(|x| my_region.replace(x)) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
+        }
+    });
+
+    return region.take()
+}
+
+/// Renders `f` with the current value of `cell`, rendering it again (from scratch) every time the cell is updated
+pub(crate) fn rerender<T, F, C> (cell: &T, mut f: F) -> Result<Node>
+where
+    T: ?Sized + CellLike,
+    F: 'static + FnMut(&T::Value) -> C,
+    C: Component
+{
+    let owner = Owner::new();
+    let region = Region::new(owner.run(|| f(&cell.get()).render())?)?;
+    let my_region = region.clone();
+
+    cell.on_update(move |x| {
+        owner.cleanup();
+        match owner.run(|| f(x).render()).and_then(|x| my_region.replace(x)) {
+            Ok(_) => {},
+            Err(e) => owner.throw(e)
+        }
+    });
+
+    return region.take()
+}
+
+/// Renders an enum-valued cell one variant at a time.
+/// `render` is called with the current value and a cell tracking it, and it's result is only torn down and rebuilt
+/// when the active variant changes. Updates that keep the same variant are forwarded to the branch through the cell,
+/// which won't ever see a value with a different variant.
+#[derive(Props)]
+pub struct Switch<V, R> {
+    pub value: V,
+    pub render: R
+}
+
+impl<V, R> Switch<V, R> {
+    #[inline]
+    pub fn new (value: V, render: R) -> Self {
+        return Self { value, render }
+    }
+}
+
+impl<V, T, R, C> Component for Switch<V, R>
+where
+    V: CellLike<Value = T>,
+    T: 'static + Clone,
+    R: 'static + FnMut(&T, &SharedCell<T>) -> C,
+    C: Component
+{
+    fn render (self) -> Result<Node> {
+        let Self { value, mut render } = self;
+
+        let owner = Owner::new();
+        let region;
+        let mut active: Discriminant<T>;
+        let mut cell: SharedCell<T>;
+
+        {
+            let value = value.get();
+            active = discriminant(value.deref());
+            cell = SharedCell::new(T::clone(&value));
+            region = Region::new(owner.run(|| render(&value, &cell).render())?)?;
+        }
+
+        let my_region = region.clone();
+        value.on_update(move |x| {
+            if discriminant(x) == active {
+                return RefMutableCell::set(&cell, x.clone())
+            }
+
+            active = discriminant(x);
+            cell = SharedCell::new(x.clone());
+            owner.cleanup();
+
+            match owner.run(|| render(x, &cell).render()).and_then(|x| my_region.replace(x)) {
+                Ok(_) => {},
+                Err(e) => owner.throw(e)
+            }
+        });
+
+        return region.take()
+    }
+}
+
+/// Condition of an `if` block inside `html!`.
+/// Cells resolve to [`ConditionCell`], which renders the selected branch again whenever the condition flips like [`Show`],
+/// and plain booleans to [`ConditionValue`], which only renders the selected branch.
+#[doc(hidden)]
+pub struct Condition<'a, T: ?Sized> (pub &'a T);
+
+#[doc(hidden)]
+pub trait ConditionCell {
+    fn choose<A: Component, B: Component> (&self, then: impl 'static + FnMut() -> A, otherwise: impl 'static + FnMut() -> B) -> Result<Node>;
+}
+
+#[doc(hidden)]
+pub trait ConditionValue {
+    fn choose<A: Component, B: Component> (&self, then: impl FnOnce() -> A, otherwise: impl FnOnce() -> B) -> Result<Node>;
+}
+
+impl<T: ?Sized + CellLike<Value = bool>> ConditionCell for Condition<'_, T> {
+    #[inline]
+    fn choose<A: Component, B: Component> (&self, then: impl 'static + FnMut() -> A, otherwise: impl 'static + FnMut() -> B) -> Result<Node> {
+        show(self.0, then, otherwise)
+    }
+}
+
+impl ConditionValue for &Condition<'_, bool> {
+    #[inline]
+    fn choose<A: Component, B: Component> (&self, then: impl FnOnce() -> A, otherwise: impl FnOnce() -> B) -> Result<Node> {
+        match *self.0 {
+            true => then().render(),
+            false => otherwise().render()
+        }
+    }
+}
+
+/// Scrutinee of a `match` block inside `html!`.
+/// Cells resolve to [`ScrutineeCell`], which renders the matching arm again on every update,
+/// and every other value to [`ScrutineeValue`], which renders it once.
+#[doc(hidden)]
+pub struct Scrutinee<'a, T: ?Sized> (pub &'a T);
+
+#[doc(hidden)]
+pub trait ScrutineeCell {
+    type Value: ?Sized;
+    fn switch<F: 'static + FnMut(&Self::Value) -> C, C: Component> (&self, f: F) -> Result<Node>;
+}
+
+#[doc(hidden)]
+pub trait ScrutineeValue {
+    type Value: ?Sized;
+    fn switch<F: FnOnce(&Self::Value) -> C, C: Component> (&self, f: F) -> Result<Node>;
+}
+
+impl<T: ?Sized + CellLike> ScrutineeCell for Scrutinee<'_, T> {
+    type Value = T::Value;
+
+    #[inline]
+    fn switch<F: 'static + FnMut(&Self::Value) -> C, C: Component> (&self, f: F) -> Result<Node> {
+        rerender(self.0, f)
+    }
+}
+
+impl<T: ?Sized> ScrutineeValue for &Scrutinee<'_, T> {
+    type Value = T;
+
+    #[inline]
+    fn switch<F: FnOnce(&Self::Value) -> C, C: Component> (&self, f: F) -> Result<Node> {
+        f(self.0).render()
+    }
+}
\ No newline at end of file
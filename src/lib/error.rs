@@ -0,0 +1,145 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use macros::Props;
+use wasm_bindgen::JsValue;
+use crate::component::{Component, Node};
+use crate::owner::{Owner, ErrorHandler};
+use crate::region::Region;
+use crate::{dynui, Result};
+
+/// Callback that tears down an [`ErrorBoundary`]'s fallback and renders it's children again
+#[derive(Clone)]
+pub struct Reset (Rc<dyn Fn()>);
+
+impl Reset {
+    /// Retries rendering the boundary's children
+    #[inline]
+    pub fn reset (&self) {
+        (self.0)()
+    }
+}
+
+/// Catches the errors raised while rendering or re-rendering it's descendants.
+/// When an error is caught, `children`'s nodes are torn down, and `fallback` is rendered with the error
+/// and a [`Reset`] callback that re-renders `children` when called.
+///
+/// Errors raised while rendering `fallback` are handed to the closest enclosing boundary.
+#[derive(Props)]
+pub struct ErrorBoundary<C, F> {
+    pub children: C,
+    pub fallback: F
+}
+
+impl<C, F> ErrorBoundary<C, F> {
+    #[inline]
+    pub fn new (children: C, fallback: F) -> Self {
+        return Self { children, fallback }
+    }
+}
+
+impl<C, R, F, S> Component for ErrorBoundary<C, F>
+where
+    C: 'static + FnMut() -> R,
+    R: 'static + Component,
+    F: 'static + FnMut(JsValue, Reset) -> S,
+    S: 'static + Component
+{
+    fn render (self) -> Result<Node> {
+        let Self { children, fallback } = self;
+        let scope = Owner::new();
+        let region = Region::empty()?;
+
+        let boundary = Rc::new(RefCell::new(Boundary {
+            children,
+            fallback,
+            region: region.clone(),
+            content: scope.child(),
+            recovery: scope.child(),
+            scope
+        }));
+
+        mount(&boundary)?;
+        return region.take()
+    }
+}
+
+struct Boundary<C, F> {
+    children: C,
+    fallback: F,
+    region: Region,
+    scope: Owner,
+    content: Owner,
+    recovery: Owner
+}
+
+/// Renders the boundary's children, or it's fallback if they fail
+fn mount<C, R, F, S> (boundary: &Rc<RefCell<Boundary<C, F>>>) -> Result<()>
+where
+    C: 'static + FnMut() -> R,
+    R: 'static + Component,
+    F: 'static + FnMut(JsValue, Reset) -> S,
+    S: 'static + Component
+{
+    let mut this = boundary.borrow_mut();
+    let this = &mut *this;
+
+    this.recovery.cleanup();
+    this.content.cleanup();
+
+    let weak = Rc::downgrade(boundary);
+    this.content.provide(ErrorHandler(Rc::new(move |e| defer(&weak, move |boundary| fail::<C, R, F, S>(boundary, e)))));
+
+    let result = this.content.run(|| (this.children)().render());
+    return match result.and_then(|x| this.region.replace(x)) {
+        Ok(_) => Ok(()),
+        Err(e) => recover(boundary, this, e)
+    }
+}
+
+/// Tears down the boundary's children and renders it's fallback
+fn fail<C, R, F, S> (boundary: &Rc<RefCell<Boundary<C, F>>>, e: JsValue) -> Result<()>
+where
+    C: 'static + FnMut() -> R,
+    R: 'static + Component,
+    F: 'static + FnMut(JsValue, Reset) -> S,
+    S: 'static + Component
+{
+    let mut this = boundary.borrow_mut();
+    return recover(boundary, &mut this, e)
+}
+
+fn recover<C, R, F, S> (boundary: &Rc<RefCell<Boundary<C, F>>>, this: &mut Boundary<C, F>, e: JsValue) -> Result<()>
+where
+    C: 'static + FnMut() -> R,
+    R: 'static + Component,
+    F: 'static + FnMut(JsValue, Reset) -> S,
+    S: 'static + Component
+{
+    this.content.cleanup();
+    this.recovery.cleanup();
+
+    let weak = Rc::downgrade(boundary);
+    let reset = Reset(Rc::new(move || defer(&weak, mount::<C, R, F, S>)));
+
+    let result = this.recovery.run(|| (this.fallback)(e, reset).render());
+    return result.and_then(|x| this.region.replace(x))
+}
+
+/// Executes `f` with the boundary as soon as it isn't in use.
+/// Errors are reported to the boundary's parent.
+fn defer<C: 'static, F: 'static> (boundary: &Weak<RefCell<Boundary<C, F>>>, f: impl 'static + FnOnce(&Rc<RefCell<Boundary<C, F>>>) -> Result<()>) {
+    let boundary = match boundary.upgrade() {
+        Some(x) => x,
+        None => return
+    };
+
+    if boundary.try_borrow_mut().is_err() {
+        let boundary = Rc::downgrade(&boundary);
+        return wasm_bindgen_futures::spawn_local(async move { defer(&boundary, f) })
+    }
+
+    if let Err(e) = f(&boundary) {
+        let scope = boundary.borrow().scope.clone();
+        scope.throw(e)
+    }
+}
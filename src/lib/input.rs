@@ -1,41 +1,28 @@
 use std::fmt::Display;
 use into_string::FromString;
-use into_string::IntoCowStr;
 use macros::component;
 use macros::html;
-use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, HtmlButtonElement};
 use crate::Result;
 use crate::cell::CellLike;
 use crate::cell::MutableCell;
-use crate::cell::SharedCell;
 use crate::dynui;
 use crate::dynui::Element;
+use crate::prop::RefProperty;
 
 #[component]
-pub fn Input<'a, V: 'static + MutableCell> (ty: &'a str, mut value: V) -> Result<Element> 
+pub fn Input<'a, V: 'static + MutableCell> (ty: &'a str, value: V) -> Result<Element<HtmlInputElement>> 
 where
-    <V as CellLike>::Value: FromString,
+    <V as CellLike>::Value: FromString + RefProperty,
     <<V as CellLike>::Value as FromString>::Err: Display
 {
-    let element = html! {
-        <input r#type={ty} />
-    }?;
-
-    let my_element: web_sys::HtmlInputElement = JsCast::unchecked_into(element.0.clone());
-    element.add_listener("keyup", move |_| {
-        let text = my_element.value();
-        let v = match <V::Value as FromString>::from_string(text) {
-            Ok(x) => x,
-            Err(e) => wasm_bindgen::throw_str(&e.into_cow_str())
-        };
-        value.set(v)
-    })?;
-
-    return Ok(element)
+    return html! {
+        <input r#type={ty} bind:value={value} />
+    }
 }
 
 #[component]
-pub fn Button<'a, F: 'static + FnMut(web_sys::Event)> (text: &'a str, default: bool, mut onclick: F) -> Result<Element> {
+pub fn Button<'a, F: 'static + FnMut(web_sys::Event)> (text: &'a str, #[prop(default)] default: bool, mut onclick: F) -> Result<Element<HtmlButtonElement>> {
     let button = html! {
         <button>{text}</button>
     }?;
@@ -1,5 +1,12 @@
-use macros::{component, html};
-use crate::component::{Component, RefComponent, Element};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Deref;
+use macros::{component, html, Props};
+use crate::cell::CellLike;
+use crate::component::{Component, RefComponent, Element, Node, fragment};
+use crate::lib::control::rerender;
+use crate::owner::Owner;
+use crate::region::Region;
 use crate::{dynui, Result, create_element};
 
 #[component]
@@ -26,4 +33,230 @@ pub fn RefList<'a, I: IntoIterator<Item = &'a T>, T: 'a + RefComponent> (ordered
     }
 
     return Ok(list)
-}
\ No newline at end of file
+}
+
+/// Keyed list rendering.
+/// Every time `each` is updated, the previous items are reconciled with the new ones by their `key`,
+/// reusing the already rendered nodes, rendering only new items, removing the missing ones
+/// and moving as few nodes as possible.
+///
+/// Items are rendered once per key, so `render` won't be called again for an item whose key is still present.
+/// Keys must be unique: a list with repeated keys is rejected with an error, leaving the rendered items untouched.
+#[derive(Props)]
+pub struct For<E, K, R> {
+    pub each: E,
+    pub key: K,
+    pub render: R
+}
+
+impl<E, K, R> For<E, K, R> {
+    #[inline]
+    pub fn new (each: E, key: K, render: R) -> Self {
+        return Self { each, key, render }
+    }
+}
+
+impl<E, T, K, Id, R, C> Component for For<E, K, R>
+where
+    E: CellLike<Value = Vec<T>>,
+    K: 'static + FnMut(&T) -> Id,
+    Id: 'static + Eq + Hash,
+    R: 'static + FnMut(&T) -> C,
+    C: Component
+{
+    #[inline]
+    fn render (self) -> Result<Node> {
+        keyed(&self.each, self.key, self.render)
+    }
+}
+
+pub(crate) fn keyed<E, T, K, Id, R, C> (each: &E, mut key: K, mut render: R) -> Result<Node>
+where
+    E: ?Sized + CellLike<Value = Vec<T>>,
+    K: 'static + FnMut(&T) -> Id,
+    Id: 'static + Eq + Hash,
+    R: 'static + FnMut(&T) -> C,
+    C: Component
+{
+    let owner = Owner::new();
+    let region = Region::empty()?;
+    let mut entries = Vec::<Entry<Id>>::new();
+
+    reconcile(&owner, &region, &mut entries, each.get().deref(), &mut key, &mut render)?;
+
+    let my_region = region.clone();
+    each.on_update(move |items| match reconcile(&owner, &my_region, &mut entries, items, &mut key, &mut render) {
+        Ok(_) => {},
+        Err(e) => owner.throw(e)
+    });
+
+    return region.take()
+}
+
+/// Iterable of a `for` block inside `html!`.
+/// Cells of vectors resolve to [`EachCell`], which renders the list again on every update
+/// (reconciling it's items by key when one is given), and every other iterable to [`EachValue`], which renders it once.
+#[doc(hidden)]
+pub struct Each<I> (std::cell::Cell<Option<I>>);
+
+impl<I> Each<I> {
+    #[inline]
+    pub fn new (iter: I) -> Self {
+        return Self(std::cell::Cell::new(Some(iter)))
+    }
+
+    #[inline]
+    fn take (&self) -> Result<I> {
+        self.0.take().ok_or_else(|| wasm_bindgen::JsValue::from_str("iterable has already been consumed"))
+    }
+}
+
+#[doc(hidden)]
+pub trait EachCell {
+    type Item;
+    fn each<R: 'static + FnMut(&Self::Item) -> C, C: Component> (&self, render: R) -> Result<Node>;
+    fn each_keyed<K: 'static + FnMut(&Self::Item) -> Id, Id: 'static + Eq + Hash, R: 'static + FnMut(&Self::Item) -> C, C: Component> (&self, key: K, render: R) -> Result<Node>;
+}
+
+#[doc(hidden)]
+pub trait EachValue {
+    type Item;
+    fn each<R: FnMut(Self::Item) -> C, C: Component> (&self, render: R) -> Result<Node>;
+    fn each_keyed<K: FnMut(&Self::Item) -> Id, Id, R: FnMut(Self::Item) -> C, C: Component> (&self, key: K, render: R) -> Result<Node>;
+}
+
+impl<E: ?Sized + CellLike<Value = Vec<T>>, T> EachCell for Each<&E> {
+    type Item = T;
+
+    #[inline]
+    fn each<R: 'static + FnMut(&T) -> C, C: Component> (&self, mut render: R) -> Result<Node> {
+        rerender(self.take()?, move |items: &Vec<T>| fragment(items.iter().map(&mut render)))
+    }
+
+    #[inline]
+    fn each_keyed<K: 'static + FnMut(&T) -> Id, Id: 'static + Eq + Hash, R: 'static + FnMut(&T) -> C, C: Component> (&self, key: K, render: R) -> Result<Node> {
+        keyed(self.take()?, key, render)
+    }
+}
+
+impl<I: IntoIterator> EachValue for &Each<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn each<R: FnMut(I::Item) -> C, C: Component> (&self, render: R) -> Result<Node> {
+        fragment(self.take()?.into_iter().map(render))
+    }
+
+    /// Plain iterables are only rendered once, so their items don't need to be reconciled
+    #[inline]
+    fn each_keyed<K: FnMut(&I::Item) -> Id, Id, R: FnMut(I::Item) -> C, C: Component> (&self, _key: K, render: R) -> Result<Node> {
+        self.each(render)
+    }
+}
+
+struct Entry<Id> {
+    id: Id,
+    region: Region,
+    owner: Owner
+}
+
+fn reconcile<T, Id: Eq + Hash, C: Component> (
+    parent: &Owner,
+    region: &Region,
+    entries: &mut Vec<Entry<Id>>,
+    items: &[T],
+    key: &mut impl FnMut(&T) -> Id,
+    render: &mut impl FnMut(&T) -> C
+) -> Result<()> {
+    // Every key must identify a single item, otherwise the regions of the repeated ones couldn't be told apart
+    let ids = items.iter().map(&mut *key).collect::<Vec<_>>();
+    let mut unique = HashSet::with_capacity(ids.len());
+    if !ids.iter().all(|id| unique.insert(id)) {
+        return Err(wasm_bindgen::JsValue::from_str("duplicate key in keyed list"))
+    }
+
+    let sources = {
+        let prev = entries.iter().enumerate().map(|(i, x)| (&x.id, i)).collect::<HashMap<_, _>>();
+        ids.iter().map(|id| prev.get(id).copied()).collect::<Vec<_>>()
+    };
+
+    // Render the new items before touching the current entries, so that a failure leaves the list as it was
+    let mut rendered = Vec::with_capacity(items.len());
+    for (item, source) in items.iter().zip(&sources) {
+        if source.is_some() {
+            rendered.push(None);
+            continue
+        }
+
+        let owner = parent.child();
+        match owner.run(|| render(item).render()).and_then(Region::new) {
+            Ok(region) => rendered.push(Some((region, owner))),
+            Err(e) => {
+                owner.cleanup();
+                rendered.into_iter().flatten().for_each(|(_, owner)| owner.cleanup());
+                return Err(e)
+            }
+        }
+    }
+
+    // Reuse the regions whose keys are still present, and dispose of the rest
+    let mut prev = entries.drain(..).map(Some).collect::<Vec<_>>();
+    for ((id, source), rendered) in ids.into_iter().zip(&sources).zip(rendered) {
+        let (region, owner) = match (source, rendered) {
+            (Some(i), _) => prev[*i].take().map(|Entry { region, owner, .. }| (region, owner)),
+            (None, rendered) => rendered
+        }.expect("every item has either a previous entry or a rendered region");
+
+        entries.push(Entry { id, region, owner });
+    }
+
+    let mut result = Ok(());
+    for Entry { region, owner, .. } in prev.into_iter().flatten() {
+        owner.cleanup();
+        if let Err(e) = region.remove() {
+            result = result.and(Err(e));
+        }
+    }
+
+    // Items in the longest increasing subsequence are already in order, everything else gets moved
+    let stays = longest_increasing_subsequence(&sources);
+    let mut anchor = region.end().clone();
+
+    for (Entry { region, .. }, stays) in entries.iter().zip(stays).rev() {
+        if !stays {
+            region.move_before(&anchor)?;
+        }
+        anchor = region.start().clone();
+    }
+
+    return result
+}
+
+/// Returns which of the present `sources` form the longest strictly increasing subsequence
+fn longest_increasing_subsequence (sources: &[Option<usize>]) -> Vec<bool> {
+    let mut tails = Vec::<usize>::new();
+    let mut parents = vec![None; sources.len()];
+
+    for (i, source) in sources.iter().enumerate() {
+        let Some(source) = *source else { continue };
+        let pos = tails.partition_point(|&j| sources[j].is_some_and(|x| x < source));
+
+        if pos > 0 {
+            parents[i] = Some(tails[pos - 1]);
+        }
+
+        match tails.get_mut(pos) {
+            Some(x) => *x = i,
+            None => tails.push(i)
+        }
+    }
+
+    let mut result = vec![false; sources.len()];
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result[i] = true;
+        current = parents[i];
+    }
+
+    return result
+}
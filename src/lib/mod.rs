@@ -1 +1 @@
-mmod! { input, list, r#async }
\ No newline at end of file
+mmod! { input, list, r#async, control, portal, error }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use macros::component;
+use crate::component::{Component, Node};
+use crate::owner::{Owner, on_cleanup};
+use crate::region::Region;
+use crate::{dynui, Result, CONTEXT};
+
+/// Renders `children` into `target` (or the document's body, if omitted), instead of the portal's position in the DOM.
+/// The children are still owned by the portal's logical parent, so they can access it's context,
+/// and are removed from `target` when the parent is cleaned up.
+#[component]
+pub fn Portal<C: Component> (#[prop(optional)] target: Option<web_sys::Element>, children: C) -> Result<Node> {
+    let owner = Owner::new();
+    let content = Region::new(owner.run(|| children.render())?)?;
+
+    let target = match target {
+        Some(x) => x,
+        None => CONTEXT.with(|ctx| ctx.body.clone().into())
+    };
+
+    target.append_child(&content.take()?.0)?;
+    on_cleanup(move || {
+        owner.cleanup();
+        if let Err(e) = content.remove() {
+            owner.throw(e)
+        }
+    });
+
+    return Region::empty()?.take()
+}
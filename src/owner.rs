@@ -0,0 +1,178 @@
+use std::{rc::{Rc, Weak}, cell::RefCell, any::{Any, TypeId}, collections::HashMap};
+use wasm_bindgen::JsValue;
+
+thread_local! {
+    static OWNER: RefCell<Option<Owner>> = RefCell::new(None);
+}
+
+/// A node of the ownership tree.
+/// Owners keep track of the logical hierarchy of rendered components, independently of where their nodes end up in the DOM.
+/// They're used to provide context values to descendants, and to run cleanup callbacks when a part of the tree is torn down.
+#[derive(Clone)]
+pub struct Owner (Rc<OwnerInner>);
+
+struct OwnerInner {
+    parent: Option<Owner>,
+    children: RefCell<Vec<Weak<OwnerInner>>>,
+    cleanups: RefCell<Vec<Box<dyn FnOnce()>>>,
+    contexts: RefCell<HashMap<TypeId, Rc<dyn Any>>>
+}
+
+impl Owner {
+    /// Creates a new owner, child of the current one (if any)
+    #[inline]
+    pub fn new () -> Self {
+        return match Self::current() {
+            Some(parent) => parent.child(),
+            None => Self::root()
+        }
+    }
+
+    /// Creates a new owner without a parent
+    #[inline]
+    pub fn root () -> Self {
+        return Self::with_parent(None)
+    }
+
+    /// Creates a new owner, child of `self`
+    pub fn child (&self) -> Self {
+        let child = Self::with_parent(Some(self.clone()));
+
+        let mut children = self.0.children.borrow_mut();
+        children.retain(|x| x.strong_count() > 0);
+        children.push(Rc::downgrade(&child.0));
+        drop(children);
+
+        return child
+    }
+
+    #[inline]
+    fn with_parent (parent: Option<Owner>) -> Self {
+        return Self(Rc::new(OwnerInner {
+            parent,
+            children: Default::default(),
+            cleanups: Default::default(),
+            contexts: Default::default()
+        }))
+    }
+
+    /// Returns the owner of the component currently being rendered
+    #[inline]
+    pub fn current () -> Option<Self> {
+        OWNER.with(|x| x.borrow().clone())
+    }
+
+    /// Returns the owner's parent, if any
+    #[inline]
+    pub fn parent (&self) -> Option<&Self> {
+        self.0.parent.as_ref()
+    }
+
+    /// Executes `f` with `self` as the current owner
+    pub fn run<T, F: FnOnce() -> T> (&self, f: F) -> T {
+        struct Restore (Option<Owner>);
+
+        impl Drop for Restore {
+            #[inline]
+            fn drop(&mut self) {
+                let prev = self.0.take();
+                OWNER.with(|x| *x.borrow_mut() = prev);
+            }
+        }
+
+        let _restore = Restore(OWNER.with(|x| x.replace(Some(self.clone()))));
+        return f()
+    }
+
+    /// Sets up a callback to be executed when the owner is cleaned up
+    #[inline]
+    pub fn on_cleanup<F: 'static + FnOnce()> (&self, f: F) {
+        self.0.cleanups.borrow_mut().push(Box::new(f))
+    }
+
+    /// Cleans up the owner's descendants, and then the owner itself, discarding any context provided to it.
+    /// The owner may still be used afterwards.
+    pub fn cleanup (&self) {
+        let children = core::mem::take(&mut *self.0.children.borrow_mut());
+        for child in children.into_iter().rev().filter_map(|x| x.upgrade()) {
+            Owner(child).cleanup()
+        }
+
+        let cleanups = core::mem::take(&mut *self.0.cleanups.borrow_mut());
+        for f in cleanups.into_iter().rev() {
+            f()
+        }
+
+        self.0.contexts.borrow_mut().clear();
+    }
+
+    /// Provides `value` as context to the owner and all of it's descendants
+    #[inline]
+    pub fn provide<T: 'static> (&self, value: T) {
+        self.0.contexts.borrow_mut().insert(TypeId::of::<T>(), Rc::new(value));
+    }
+
+    /// Returns the closest context value of type `T` provided to the owner or any of it's ancestors
+    pub fn context<T: 'static + Clone> (&self) -> Option<T> {
+        let mut current = Some(self);
+        while let Some(owner) = current {
+            if let Some(value) = owner.0.contexts.borrow().get(&TypeId::of::<T>()) {
+                return value.downcast_ref::<T>().cloned()
+            }
+            current = owner.parent();
+        }
+
+        return None
+    }
+
+    /// Reports an error raised outside of a render call (i.e. while reacting to an update) to the closest error handler.
+    /// If there isn't any, the error is thrown as a JavaScript exception.
+    #[inline]
+    pub fn throw (&self, e: JsValue) {
+        match self.context::<ErrorHandler>() {
+            Some(handler) => (handler.0)(e),
+            None => wasm_bindgen::throw_val(e)
+        }
+    }
+}
+
+/// Handler for the errors reported to an owner and it's descendants
+#[derive(Clone)]
+pub(crate) struct ErrorHandler (pub Rc<dyn Fn(JsValue)>);
+
+/// Sets up a callback to be executed when the current owner is cleaned up.
+/// If there isn't any current owner, the callback is never executed.
+#[inline]
+pub fn on_cleanup<F: 'static + FnOnce()> (f: F) {
+    if let Some(owner) = Owner::current() {
+        owner.on_cleanup(f)
+    }
+}
+
+/// Reports an error to the closest error handler of the current owner.
+/// If there isn't any, the error is thrown as a JavaScript exception.
+#[inline]
+pub fn throw (e: JsValue) {
+    match Owner::current() {
+        Some(owner) => owner.throw(e),
+        None => wasm_bindgen::throw_val(e)
+    }
+}
+
+/// Provides `value` as context to the current owner and all of it's descendants
+#[inline]
+pub fn provide_context<T: 'static> (value: T) {
+    match Owner::current() {
+        Some(owner) => owner.provide(value),
+        None => {
+            #[cfg(debug_assertions)]
+            crate::jseprintln!("context provided outside of an owner")
+        }
+    }
+}
+
+/// Returns the closest context value of type `T` provided to the current owner or any of it's ancestors
+#[inline]
+pub fn use_context<T: 'static + Clone> () -> Option<T> {
+    Owner::current()?.context()
+}
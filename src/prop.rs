@@ -0,0 +1,131 @@
+use std::{rc::Rc, sync::Arc, borrow::Cow};
+use wasm_bindgen::JsValue;
+use crate::Result;
+
+/// Sets the property `name` of `element` to `value`
+#[inline]
+pub(crate) fn set (element: &web_sys::Element, name: &str, value: &JsValue) -> Result<()> {
+    js_sys::Reflect::set(element, &JsValue::from_str(name), value).map(|_| ())
+}
+
+/// DOM property value that can be rendered with ownership
+pub trait Property {
+    /// Renders the value into the property `name` of `element`
+    fn render (self, element: &web_sys::Element, name: &str) -> Result<()>;
+}
+
+/// DOM property value that can be rendered with a reference
+pub trait RefProperty: MutProperty {
+    /// Renders the value into the property `name` of `element`
+    fn render (&self, element: &web_sys::Element, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + RefProperty> Property for &T {
+    #[inline]
+    default fn render (self, element: &web_sys::Element, name: &str) -> Result<()> {
+        RefProperty::render(self, element, name)
+    }
+}
+
+impl<T: ?Sized + RefProperty> MutProperty for T {
+    #[inline]
+    default fn render (&mut self, element: &web_sys::Element, name: &str) -> Result<()> {
+        RefProperty::render(self, element, name)
+    }
+}
+
+impl RefProperty for JsValue {
+    #[inline]
+    fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+        set(element, name, self)
+    }
+}
+
+impl RefProperty for bool {
+    #[inline]
+    fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+        set(element, name, &JsValue::from_bool(*self))
+    }
+}
+
+impl<T: RefProperty> RefProperty for Option<T> {
+    #[inline]
+    fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+        match self {
+            Some(x) => RefProperty::render(x, element, name),
+            None => set(element, name, &JsValue::NULL)
+        }
+    }
+}
+
+impl<T: Property> Property for Option<T> {
+    #[inline]
+    fn render (self, element: &web_sys::Element, name: &str) -> Result<()> {
+        match self {
+            Some(x) => x.render(element, name),
+            None => set(element, name, &JsValue::NULL)
+        }
+    }
+}
+
+macro_rules! impl_owned {
+    ($($t:ty),+) => {
+        $(
+            impl Property for $t {
+                #[inline]
+                fn render (self, element: &web_sys::Element, name: &str) -> Result<()> {
+                    RefProperty::render(&self, element, name)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_str {
+    ($($t:ty),+) => {
+        $(
+            impl RefProperty for $t {
+                #[inline]
+                fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+                    set(element, name, &JsValue::from_str(self))
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_num {
+    ($($t:ty),+) => {
+        $(
+            impl RefProperty for $t {
+                #[inline]
+                fn render (&self, element: &web_sys::Element, name: &str) -> Result<()> {
+                    set(element, name, &JsValue::from_f64(*self as f64))
+                }
+            }
+        )+
+
+        impl_owned! { $($t),+ }
+    };
+}
+
+impl_str! { &str, str, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+impl_owned! { JsValue, bool, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+impl_num! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64
+}
+
+/// DOM property value that can be rendered with mutable reference
+pub trait MutProperty {
+    /// Renders the value into the property `name` of `element`
+    fn render (&mut self, element: &web_sys::Element, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + MutProperty> Property for &mut T {
+    #[inline]
+    default fn render (self, element: &web_sys::Element, name: &str) -> Result<()> {
+        MutProperty::render(self, element, name)
+    }
+}
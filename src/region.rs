@@ -0,0 +1,123 @@
+use web_sys::{Comment, DocumentFragment};
+use crate::{Result, component::{Component, Node}, jseprintln};
+
+/// A reactive section of the DOM, delimited by a pair of comment anchors.
+/// Everything between the anchors belongs to the region, so it may contain zero, one or many nodes
+/// and be replaced as many times as needed, even when it's contents were rendered into a [`DocumentFragment`].
+#[derive(Debug, Clone)]
+pub struct Region {
+    start: Comment,
+    end: Comment
+}
+
+impl Region {
+    /// Creates a new region containing the specified `content`
+    #[inline]
+    pub fn new (content: Node) -> Result<Self> {
+        let region = Self::empty()?;
+        region.end.before_with_node_1(&content.0)?;
+        return Ok(region)
+    }
+
+    /// Creates a new region without any content
+    pub fn empty () -> Result<Self> {
+        let fragment = DocumentFragment::new()?;
+        let start = Comment::new_with_data("")?;
+        let end = Comment::new_with_data("")?;
+
+        fragment.append_child(&start)?;
+        fragment.append_child(&end)?;
+        return Ok(Self { start, end })
+    }
+
+    /// Returns the anchor at the start of the region
+    #[inline(always)]
+    pub fn start (&self) -> &web_sys::Node {
+        &self.start
+    }
+
+    /// Returns the anchor at the end of the region
+    #[inline(always)]
+    pub fn end (&self) -> &web_sys::Node {
+        &self.end
+    }
+
+    /// Returns the nodes currently found between the region's anchors
+    pub fn nodes (&self) -> Vec<web_sys::Node> {
+        let mut nodes = Vec::new();
+        let mut current = self.start.next_sibling();
+
+        while let Some(node) = current {
+            if node.is_same_node(Some(&self.end)) { break }
+            current = node.next_sibling();
+            nodes.push(node);
+        }
+
+        return nodes
+    }
+
+    /// Removes all the nodes between the region's anchors
+    pub fn clear (&self) -> Result<()> {
+        let parent = match self.end.parent_node() {
+            Some(x) => x,
+            None => {
+                #[cfg(debug_assertions)]
+                jseprintln!("region anchor doesn't have a parent");
+                return Ok(())
+            }
+        };
+
+        for node in self.nodes() {
+            parent.remove_child(&node)?;
+        }
+
+        return Ok(())
+    }
+
+    /// Replaces the contents of the region with `content`
+    #[inline]
+    pub fn replace (&self, content: Node) -> Result<()> {
+        self.clear()?;
+        return self.end.before_with_node_1(&content.0)
+    }
+
+    /// Detaches the region (anchors included) from it's current parent, returning a [`DocumentFragment`] with it's nodes.
+    /// The region remains valid, and may be re-inserted anywhere else by appending the returned node.
+    pub fn take (&self) -> Result<Node> {
+        let fragment = DocumentFragment::new()?;
+        let mut current = Some(web_sys::Node::clone(&self.start));
+
+        while let Some(node) = current {
+            let is_end = node.is_same_node(Some(&self.end));
+            current = node.next_sibling();
+            fragment.append_child(&node)?;
+            if is_end { break }
+        }
+
+        return unsafe { Ok(Node::new(fragment)) }
+    }
+
+    /// Moves the region (anchors included) right before `anchor`
+    pub fn move_before (&self, anchor: &web_sys::Node) -> Result<()> {
+        let parent = match anchor.parent_node() {
+            Some(x) => x,
+            None => return Err(wasm_bindgen::JsValue::from_str("anchor node doesn't have a parent"))
+        };
+
+        let nodes = self.take()?;
+        return parent.insert_before(&nodes.0, Some(anchor)).map(|_| ())
+    }
+
+    /// Removes the region (anchors included) from the DOM
+    #[inline]
+    pub fn remove (&self) -> Result<()> {
+        self.take().map(|_| ())
+    }
+}
+
+impl Component for Region {
+    #[inline]
+    fn render (self) -> Result<Node> {
+        self.take()
+    }
+}
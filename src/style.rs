@@ -0,0 +1,164 @@
+use std::{rc::Rc, sync::Arc, borrow::Cow};
+use web_sys::{CssStyleDeclaration, DomTokenList};
+use crate::Result;
+
+/// Value of a single class, which is added to the element when `true` and removed when `false`
+pub trait Class {
+    /// Adds or removes `name` from `classes` based on the value
+    fn render (self, classes: &DomTokenList, name: &str) -> Result<()>;
+}
+
+/// Class value that can be rendered with a reference
+pub trait RefClass: MutClass {
+    /// Adds or removes `name` from `classes` based on the value
+    fn render (&self, classes: &DomTokenList, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + RefClass> Class for &T {
+    #[inline]
+    default fn render (self, classes: &DomTokenList, name: &str) -> Result<()> {
+        RefClass::render(self, classes, name)
+    }
+}
+
+impl<T: ?Sized + RefClass> MutClass for T {
+    #[inline]
+    default fn render (&mut self, classes: &DomTokenList, name: &str) -> Result<()> {
+        RefClass::render(self, classes, name)
+    }
+}
+
+impl RefClass for bool {
+    #[inline]
+    fn render (&self, classes: &DomTokenList, name: &str) -> Result<()> {
+        classes.toggle_with_force(name, *self).map(|_| ())
+    }
+}
+
+impl Class for bool {
+    #[inline]
+    fn render (self, classes: &DomTokenList, name: &str) -> Result<()> {
+        RefClass::render(&self, classes, name)
+    }
+}
+
+/// Class value that can be rendered with mutable reference
+pub trait MutClass {
+    /// Adds or removes `name` from `classes` based on the value
+    fn render (&mut self, classes: &DomTokenList, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + MutClass> Class for &mut T {
+    #[inline]
+    default fn render (self, classes: &DomTokenList, name: &str) -> Result<()> {
+        MutClass::render(self, classes, name)
+    }
+}
+
+/// Value of a single CSS property, which can be rendered with ownership
+pub trait Style {
+    /// Renders the value into the property `name` of `style`
+    fn render (self, style: &CssStyleDeclaration, name: &str) -> Result<()>;
+}
+
+/// CSS property value that can be rendered with a reference
+pub trait RefStyle: MutStyle {
+    /// Renders the value into the property `name` of `style`
+    fn render (&self, style: &CssStyleDeclaration, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + RefStyle> Style for &T {
+    #[inline]
+    default fn render (self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+        RefStyle::render(self, style, name)
+    }
+}
+
+impl<T: ?Sized + RefStyle> MutStyle for T {
+    #[inline]
+    default fn render (&mut self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+        RefStyle::render(self, style, name)
+    }
+}
+
+impl<T: RefStyle> RefStyle for Option<T> {
+    #[inline]
+    fn render (&self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+        match self {
+            Some(x) => RefStyle::render(x, style, name),
+            None => style.remove_property(name).map(|_| ())
+        }
+    }
+}
+
+impl<T: Style> Style for Option<T> {
+    #[inline]
+    fn render (self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+        match self {
+            Some(x) => x.render(style, name),
+            None => style.remove_property(name).map(|_| ())
+        }
+    }
+}
+
+macro_rules! impl_owned {
+    ($($t:ty),+) => {
+        $(
+            impl Style for $t {
+                #[inline]
+                fn render (self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+                    RefStyle::render(&self, style, name)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_str {
+    ($($t:ty),+) => {
+        $(
+            impl RefStyle for $t {
+                #[inline]
+                fn render (&self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+                    style.set_property(name, self)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_display {
+    ($($t:ty),+) => {
+        $(
+            impl RefStyle for $t {
+                #[inline]
+                fn render (&self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+                    style.set_property(name, &self.to_string())
+                }
+            }
+        )+
+
+        impl_owned! { $($t),+ }
+    };
+}
+
+impl_str! { &str, str, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+impl_owned! { String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+impl_display! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64
+}
+
+/// CSS property value that can be rendered with mutable reference
+pub trait MutStyle {
+    /// Renders the value into the property `name` of `style`
+    fn render (&mut self, style: &CssStyleDeclaration, name: &str) -> Result<()>;
+}
+
+impl<T: ?Sized + MutStyle> Style for &mut T {
+    #[inline]
+    default fn render (self, style: &CssStyleDeclaration, name: &str) -> Result<()> {
+        MutStyle::render(self, style, name)
+    }
+}
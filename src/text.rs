@@ -0,0 +1,85 @@
+use std::{rc::Rc, cell::RefCell};
+use crate::{Result, cell::CellLike};
+
+/// Text node built from static pieces and interpolated values, as generated by `html!`'s `{f "..."}` syntax.
+/// Whenever an interpolated cell is updated, only it's piece is formatted again, and the node's data is replaced.
+pub struct Interpolation {
+    node: web_sys::Text,
+    pieces: Rc<RefCell<Vec<String>>>
+}
+
+impl Interpolation {
+    /// Creates a new empty interpolation
+    #[inline]
+    pub fn new () -> Result<Self> {
+        return Ok(Self {
+            node: web_sys::Text::new()?,
+            pieces: Default::default()
+        })
+    }
+
+    /// Appends a static piece of text
+    #[inline]
+    pub fn push_str (&self, s: &str) {
+        self.pieces.borrow_mut().push(s.to_string())
+    }
+
+    /// Appends a piece of text that's updated every time `cell` is
+    pub fn push_cell<T, F> (&self, cell: &T, mut f: F) where T: ?Sized + CellLike, F: 'static + FnMut(&T::Value) -> String {
+        let mut pieces = self.pieces.borrow_mut();
+        let idx = pieces.len();
+        pieces.push(f(&cell.get()));
+        drop(pieces);
+
+        let node = self.node.clone();
+        let pieces = self.pieces.clone();
+
+        cell.on_update(move |x| {
+            let mut pieces = pieces.borrow_mut();
+            pieces[idx] = f(x);
+            node.set_data(&pieces.concat())
+        })
+    }
+
+    /// Returns the text node with the current contents of the interpolation
+    #[inline]
+    pub fn finish (self) -> web_sys::Text {
+        self.node.set_data(&self.pieces.borrow().concat());
+        return self.node
+    }
+}
+
+/// Reference to an interpolated value, used by `html!` to tell cells apart from plain values.
+/// Cells resolve to [`InterpolateCell`], and every other value to [`InterpolateValue`].
+#[doc(hidden)]
+pub struct Interpolated<'a, T: ?Sized> (pub &'a T);
+
+#[doc(hidden)]
+pub trait InterpolateCell {
+    type Value: ?Sized;
+    fn interpolate<F: 'static + FnMut(&Self::Value) -> String> (&self, text: &Interpolation, f: F);
+}
+
+#[doc(hidden)]
+pub trait InterpolateValue {
+    type Value: ?Sized;
+    fn interpolate<F: 'static + FnMut(&Self::Value) -> String> (&self, text: &Interpolation, f: F);
+}
+
+impl<T: ?Sized + CellLike> InterpolateCell for Interpolated<'_, T> {
+    type Value = T::Value;
+
+    #[inline]
+    fn interpolate<F: 'static + FnMut(&Self::Value) -> String> (&self, text: &Interpolation, f: F) {
+        text.push_cell(self.0, f)
+    }
+}
+
+impl<T: ?Sized> InterpolateValue for &Interpolated<'_, T> {
+    type Value = T;
+
+    #[inline]
+    fn interpolate<F: 'static + FnMut(&Self::Value) -> String> (&self, text: &Interpolation, mut f: F) {
+        text.pieces.borrow_mut().push(f(self.0))
+    }
+}
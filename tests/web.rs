@@ -8,6 +8,7 @@ use std::{time::Duration, ops::AddAssign};
 
 use dynui::{*, macros::*, cell::{Cell, SharedCell, MutableCell, CellLike}};
 use dynui::component::*;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -121,4 +122,1064 @@ pub fn form () -> Result<()> {
 
     append_body(form)?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[wasm_bindgen_test]
+pub fn fragment_regions () -> Result<()> {
+    use dynui::region::Region;
+
+    // The region's anchors live inside a `DocumentFragment` when it's updated, without any parent element
+    let region = Region::new(html! { <span>"Before"</span> }?.into())?;
+    let fragment = region.take()?;
+    region.replace(html! { <span>"After"</span> }?.into())?;
+
+    let alpha = html! { <div>{fragment}</div> }?;
+    assert_eq!(alpha.text_content().as_deref(), Some("After"));
+
+    region.replace(html! { <span>"Attached"</span> }?.into())?;
+    assert_eq!(alpha.text_content().as_deref(), Some("Attached"));
+
+    let mut count = Cell::new(1u32);
+    let text = html! { "Count: " {&count} }?;
+    count.set(2);
+
+    let beta = html! { <p>{text}</p> }?;
+    assert_eq!(beta.text_content().as_deref(), Some("Count: 2"));
+
+    count.set(3);
+    assert_eq!(beta.text_content().as_deref(), Some("Count: 3"));
+
+    append_body(alpha)?;
+    append_body(beta)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn show () -> Result<()> {
+    use dynui::cell::RefMutableCell;
+    use dynui::lib::control::Show;
+
+    let visible = SharedCell::new(true);
+    let alpha = html! {
+        <div>
+            <Show
+                when={visible.clone()}
+                children={html! { <span>{"Visible"}</span> }}
+                fallback={html! { <span>{"Hidden"}</span> }}
+            />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Visible"));
+    let span = alpha.first_element_child().expect("the children must have been rendered");
+
+    RefMutableCell::set(&visible, false);
+    assert_eq!(alpha.text_content().as_deref(), Some("Hidden"));
+
+    RefMutableCell::set(&visible, true);
+    assert_eq!(alpha.text_content().as_deref(), Some("Visible"));
+    assert!(alpha.first_element_child().is_some_and(|x| x.is_same_node(Some(&span))));
+
+    let beta = html! {
+        <div>
+            <Show when={visible.clone()} children={html! { <span>{"Visible"}</span> }} />
+        </div>
+    }?;
+
+    RefMutableCell::set(&visible, false);
+    assert_eq!(beta.text_content().as_deref(), Some(""));
+
+    // Hidden branches are disposed, and nested markup is rendered again once it's shown
+    let disposed = std::rc::Rc::new(std::cell::Cell::new(0u32));
+    let my_disposed = disposed.clone();
+    let gamma = html! {
+        <div>
+            <Show when={visible.clone()} fallback={"Hidden"}>
+                {{
+                    let my_disposed = my_disposed.clone();
+                    dynui::owner::on_cleanup(move || my_disposed.set(my_disposed.get() + 1));
+                    "Visible"
+                }}
+            </Show>
+        </div>
+    }?;
+
+    assert_eq!(gamma.text_content().as_deref(), Some("Hidden"));
+    RefMutableCell::set(&visible, true);
+    assert_eq!(gamma.text_content().as_deref(), Some("Visible"));
+    assert_eq!(disposed.get(), 0);
+
+    RefMutableCell::set(&visible, false);
+    assert_eq!(gamma.text_content().as_deref(), Some("Hidden"));
+    assert_eq!(disposed.get(), 1);
+
+    RefMutableCell::set(&visible, true);
+    assert_eq!(gamma.text_content().as_deref(), Some("Visible"));
+    assert_eq!(disposed.get(), 1);
+
+    append_body(alpha)?;
+    append_body(beta)?;
+    append_body(gamma)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn switch () -> Result<()> {
+    use std::rc::Rc;
+    use dynui::cell::RefMutableCell;
+    use dynui::lib::control::Switch;
+
+    #[derive(Clone)]
+    enum Status {
+        Loading(u32),
+        Done(&'static str)
+    }
+
+    let status = SharedCell::new(Status::Loading(0));
+    let renders = Rc::new(std::cell::Cell::new(0u32));
+    let my_renders = renders.clone();
+
+    let alpha = html! {
+        <div>
+            <Switch value={status.clone()} render={move |value: &Status, cell: &SharedCell<Status>| {
+                my_renders.set(my_renders.get() + 1);
+                match value {
+                    Status::Loading(_) => {
+                        let progress = cell.map(|x| match x {
+                            Status::Loading(x) => format!("{x}%"),
+                            Status::Done(_) => String::new()
+                        });
+                        html! { <span>{&progress}</span> }
+                    },
+                    Status::Done(message) => html! { <span>{*message}</span> }
+                }
+            }} />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("0%"));
+    assert_eq!(renders.get(), 1);
+    let span = alpha.first_element_child().expect("the variant must have been rendered");
+
+    // Same variant: the update reaches the inner cell, and the content isn't rendered again
+    RefMutableCell::set(&status, Status::Loading(50));
+    assert_eq!(alpha.text_content().as_deref(), Some("50%"));
+    assert_eq!(renders.get(), 1);
+    assert!(alpha.first_element_child().is_some_and(|x| x.is_same_node(Some(&span))));
+
+    // Different variant: the content is rebuilt
+    RefMutableCell::set(&status, Status::Done("Finished"));
+    assert_eq!(alpha.text_content().as_deref(), Some("Finished"));
+    assert_eq!(renders.get(), 2);
+    assert!(alpha.first_element_child().is_some_and(|x| !x.is_same_node(Some(&span))));
+
+    RefMutableCell::set(&status, Status::Loading(10));
+    assert_eq!(alpha.text_content().as_deref(), Some("10%"));
+    assert_eq!(renders.get(), 3);
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn keyed () -> Result<()> {
+    use dynui::cell::RefMutableCell;
+    use dynui::lib::list::For;
+
+    let items = SharedCell::new(vec![1u32, 2, 3, 4]);
+    let alpha = html! {
+        <ul>
+            <For
+                each={items.clone()}
+                key={|x: &u32| *x}
+                render={|x: &u32| html! { <li>{&x.to_string()}</li> }}
+            />
+        </ul>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("1234"));
+    let four = alpha.last_element_child().expect("the items must have been rendered");
+
+    RefMutableCell::set(&items, vec![4, 2, 5, 1]);
+    assert_eq!(alpha.text_content().as_deref(), Some("4251"));
+    assert!(alpha.first_element_child().is_some_and(|x| x.is_same_node(Some(&four))));
+
+    RefMutableCell::set(&items, vec![]);
+    assert_eq!(alpha.child_element_count(), 0);
+
+    let duplicates = html! {
+        <ul>
+            <For
+                each={SharedCell::new(vec![1u32, 1])}
+                key={|x: &u32| *x}
+                render={|x: &u32| html! { <li>{&x.to_string()}</li> }}
+            />
+        </ul>
+    };
+    assert!(duplicates.is_err());
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn portal () -> Result<()> {
+    use dynui::lib::portal::Portal;
+    use dynui::owner::Owner;
+
+    let body = web_sys::window().and_then(|x| x.document()).and_then(|x| x.body()).expect("the document must have a body");
+    let owner = Owner::root();
+    let alpha = owner.run(|| html! {
+        <div>
+            <Portal children={html! { <span id={"portal-content"}>{"Inside the body"}</span> }} />
+        </div>
+    })?;
+
+    // The children are attached to the body, not to the portal's parent
+    assert_eq!(alpha.text_content().as_deref(), Some(""));
+    let span = body.query_selector("#portal-content")?.expect("the children must have been attached to the body");
+    assert!(span.parent_element().is_some_and(|x| x.is_same_node(Some(&body))));
+    assert_eq!(span.text_content().as_deref(), Some("Inside the body"));
+
+    append_body(alpha)?;
+    owner.cleanup();
+    assert!(!span.is_connected());
+    assert!(body.query_selector("#portal-content")?.is_none());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn error_boundary () -> Result<()> {
+    use std::{rc::Rc, cell::RefCell};
+    use dynui::lib::error::{ErrorBoundary, Reset};
+    use wasm_bindgen::JsValue;
+
+    // Fails the first time it's rendered, and succeeds once the boundary is reset
+    let attempts = Rc::new(std::cell::Cell::new(0u32));
+    let my_attempts = attempts.clone();
+    let children = move || {
+        my_attempts.set(my_attempts.get() + 1);
+        match my_attempts.get() {
+            1 => Err(JsValue::from_str("something went wrong")),
+            _ => html! { <span>"Recovered"</span> }
+        }
+    };
+
+    let reset = Rc::new(RefCell::new(None::<Reset>));
+    let my_reset = reset.clone();
+    let fallback = move |e: JsValue, reset: Reset| {
+        *my_reset.borrow_mut() = Some(reset);
+        html! { <span>{&e.as_string().unwrap_or_default()}</span> }
+    };
+
+    let alpha = html! {
+        <div>
+            <ErrorBoundary children={children} fallback={fallback} />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("something went wrong"));
+    let reset = reset.borrow().clone().expect("the fallback must have been rendered");
+
+    reset.reset();
+    assert_eq!(attempts.get(), 2);
+    assert_eq!(alpha.text_content().as_deref(), Some("Recovered"));
+
+    // Nested markup is rendered as the boundary's children
+    let beta = html! {
+        <div>
+            <ErrorBoundary fallback={|_, _| "Failed"}>
+                <span>"Safe"</span>
+            </ErrorBoundary>
+        </div>
+    }?;
+
+    assert_eq!(beta.text_content().as_deref(), Some("Safe"));
+
+    append_body(alpha)?;
+    append_body(beta)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub async fn suspense () -> Result<()> {
+    use dynui::lib::r#async::*;
+
+    let alpha = html! {
+        <div>
+            <Suspense
+                transition={false}
+                fallback={html! { <span>{"Loading..."}</span> }}
+                children={|| html! {
+                    <div>
+                        <Future fut={async { sleep(Duration::from_millis(10)).await.map(|_| "First") }} placeholder={"Waiting"} />
+                        <Future fut={async { sleep(Duration::from_millis(20)).await.map(|_| "Second") }} placeholder={"Waiting"} />
+                    </div>
+                }}
+            />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Loading..."));
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(alpha.text_content().as_deref(), Some("FirstSecond"));
+
+    // Nested markup is rendered as the boundary's children
+    let beta = html! {
+        <div>
+            <Suspense fallback={"Loading..."}>
+                <Future fut={async { sleep(Duration::from_millis(10)).await.map(|_| "Nested") }} placeholder={"Waiting"} />
+            </Suspense>
+        </div>
+    }?;
+
+    assert_eq!(beta.text_content().as_deref(), Some("Loading..."));
+    sleep(Duration::from_millis(30)).await?;
+    assert_eq!(beta.text_content().as_deref(), Some("Nested"));
+
+    append_body(alpha)?;
+    append_body(beta)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn primitives () -> Result<()> {
+    let mut count = Cell::new(0u32);
+    let alpha = html! {
+        <div>
+            <span>{&count}</span>
+            <span>{3.5f64}</span>
+            <span>{('a', true, Some("optional"), None::<&str>)}</span>
+            <ul>{vec![html! { <li>{1}</li> }, html! { <li>{2}</li> }]}</ul>
+            <input value={5u8} />
+        </div>
+    }?;
+
+    let child = |i| alpha.children().item(i).expect("the child must have been rendered");
+    assert_eq!(child(0).text_content().as_deref(), Some("0"));
+    assert_eq!(child(1).text_content().as_deref(), Some("3.5"));
+    assert_eq!(child(2).text_content().as_deref(), Some("atrueoptional"));
+    assert_eq!(child(3).text_content().as_deref(), Some("12"));
+    assert_eq!(child(3).child_element_count(), 2);
+    assert_eq!(child(4).get_attribute("value").as_deref(), Some("5"));
+
+    count.mutate(|x| x.add_assign(1));
+    assert_eq!(child(0).text_content().as_deref(), Some("1"));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn boolean_attributes () -> Result<()> {
+    let mut disabled = Cell::new(true);
+    let mut title = Cell::new(Some("Tooltip"));
+
+    let alpha = html! {
+        <button disabled={&disabled} title={&title} hidden={false}>{"Click me!"}</button>
+    }?;
+
+    assert!(alpha.has_attribute("disabled"));
+    assert_eq!(alpha.get_attribute("title").as_deref(), Some("Tooltip"));
+    assert!(!alpha.has_attribute("hidden"));
+
+    disabled.set(false);
+    assert!(!alpha.has_attribute("disabled"));
+
+    title.set(None);
+    assert!(!alpha.has_attribute("title"));
+
+    disabled.set(true);
+    title.set(Some("Again"));
+    assert!(alpha.has_attribute("disabled"));
+    assert_eq!(alpha.get_attribute("title").as_deref(), Some("Again"));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn properties () -> Result<()> {
+    let mut value = Cell::new(String::from("hello"));
+    let mut checked = Cell::new(true);
+    let alpha: Element<web_sys::HtmlInputElement> = html! {
+        <input r#type={"checkbox"} prop:value={&value} prop:checked={&checked} />
+    }?;
+
+    // Properties are set on the element, not as attributes
+    assert_eq!(alpha.value(), "hello");
+    assert!(alpha.checked());
+    assert!(!alpha.has_attribute("value"));
+    assert!(!alpha.has_attribute("checked"));
+
+    value.set(String::from("world"));
+    assert_eq!(alpha.value(), "world");
+    assert!(alpha.checked());
+
+    checked.set(false);
+    assert_eq!(alpha.value(), "world");
+    assert!(!alpha.checked());
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn bindings () -> Result<()> {
+    use dynui::cell::RefMutableCell;
+
+    let name = SharedCell::new(String::new());
+    let agree = SharedCell::new(false);
+    let color = SharedCell::new(String::from("red"));
+    let toppings = SharedCell::new(Vec::<String>::new());
+
+    let form = html! {
+        <form>
+            <input r#type={"text"} bind:value={name.clone()} />
+            <textarea bind:value={name.clone()}></textarea>
+            <input r#type={"checkbox"} bind:checked={agree.clone()} />
+            <input r#type={"radio"} value={"red"} bind:group={color.clone()} />
+            <input r#type={"radio"} value={"blue"} bind:group={color.clone()} />
+            <input r#type={"checkbox"} value={"cheese"} bind:group={toppings.clone()} />
+            <select bind:value={color.clone()}>
+                <option value={"red"}>{"Red"}</option>
+                <option value={"blue"}>{"Blue"}</option>
+            </select>
+        </form>
+    }?;
+
+    append_body(form)?;
+    RefMutableCell::set(&name, String::from("reset"));
+    RefMutableCell::set(&color, String::from("blue"));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn select_binding () -> Result<()> {
+    use dynui::cell::RefMutableCell;
+
+    let color = SharedCell::new(String::from("blue"));
+    let select: Element<web_sys::HtmlSelectElement> = html! {
+        <select bind:value={color.clone()}>
+            <option value={"red"}>{"Red"}</option>
+            <option value={"green"}>{"Green"}</option>
+            <option value={"blue"}>{"Blue"}</option>
+        </select>
+    }?;
+
+    assert_eq!(select.value(), "blue");
+    RefMutableCell::set(&color, String::from("green"));
+    assert_eq!(select.value(), "green");
+
+    append_body(select)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn events () -> Result<()> {
+    use std::rc::Rc;
+    use std::cell::Cell as Counter;
+    use dynui::cell::RefMutableCell;
+
+    let clicks = SharedCell::new(0u32);
+    let my_clicks = clicks.clone();
+    let outer = Rc::new(Counter::new(0u32));
+    let my_outer = outer.clone();
+    let once = Rc::new(Counter::new(0u32));
+    let my_once = once.clone();
+    let submitted = Rc::new(Counter::new(None::<bool>));
+    let my_submitted = submitted.clone();
+    let scrolled = Rc::new(Counter::new(None::<bool>));
+    let my_scrolled = scrolled.clone();
+
+    let alpha = html! {
+        <div on:submit={move |_| my_outer.set(my_outer.get() + 1)}>
+            <form
+                on:submit|prevent_default|stop_propagation={move |e| my_submitted.set(Some(e.default_prevented()))}
+                on:scroll|passive|capture={move |e| {
+                    e.prevent_default();
+                    my_scrolled.set(Some(e.default_prevented()))
+                }}
+            >
+                <button on:click={move |_| RefMutableCell::mutate(&my_clicks, |x| x.add_assign(1))}>{&clicks}</button>
+                <div></div>
+                <button on:click|once={move |_| my_once.set(my_once.get() + 1)}>{"Once"}</button>
+            </form>
+        </div>
+    }?;
+
+    let dispatch = |target: &web_sys::Element, kind: &str, bubbles: bool| -> Result<bool> {
+        let init = web_sys::EventInit::new();
+        init.set_bubbles(bubbles);
+        init.set_cancelable(true);
+        return Ok(target.dispatch_event(&web_sys::Event::new_with_event_init_dict(kind, &init)?)?)
+    };
+
+    let form = alpha.first_element_child().expect("the form must have been rendered");
+    let child = |i| form.children().item(i).expect("the child must have been rendered");
+
+    let button = child(0).unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    button.click();
+    assert_eq!(*clicks.get(), 2);
+    assert_eq!(button.text_content().as_deref(), Some("2"));
+
+    // `once` removes the listener after it's first call
+    let button = child(2).unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    button.click();
+    assert_eq!(once.get(), 1);
+
+    // `prevent_default` cancels the event before the handler runs, and `stop_propagation` keeps it from reaching the outer div
+    assert!(!dispatch(&form, "submit", true)?);
+    assert_eq!(submitted.get(), Some(true));
+    assert_eq!(outer.get(), 0);
+
+    // `capture` catches the (non bubbling) event on it's way to the inner div, and `passive` ignores `prevent_default`
+    assert!(dispatch(&child(1), "scroll", false)?);
+    assert_eq!(scrolled.get(), Some(false));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn class_and_style () -> Result<()> {
+    let mut active = Cell::new(false);
+    let mut width = Cell::new(String::from("10px"));
+
+    let alpha = html! {
+        <div class={"item"} class:active={&active} class:visible={true} style:width={&width} style:height={Some("5px")}>
+            {"Styled"}
+        </div>
+    }?;
+
+    let classes = alpha.class_list();
+    let style = alpha.style()?;
+    assert!(classes.contains("item"));
+    assert!(!classes.contains("active"));
+    assert!(classes.contains("visible"));
+    assert_eq!(style.get_property_value("width")?, "10px");
+    assert_eq!(style.get_property_value("height")?, "5px");
+
+    active.set(true);
+    assert!(classes.contains("active"));
+    assert!(classes.contains("item"));
+
+    width.set(String::from("20px"));
+    assert_eq!(style.get_property_value("width")?, "20px");
+    assert_eq!(style.get_property_value("height")?, "5px");
+
+    active.set(false);
+    assert!(!classes.contains("active"));
+    assert_eq!(classes.length(), 2);
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn attribute_names () -> Result<()> {
+    let alpha = html! {
+        <div data-id={"42"} aria-label={"Container"} "data-2x"={"escaped"}>
+            <meta http-equiv={"refresh"} content={"30"} />
+            <a xlink:href={"#target"} style:background-color={"red"} class:is-active={true}>{"Link"}</a>
+        </div>
+    }?;
+
+    assert_eq!(alpha.get_attribute("data-id").as_deref(), Some("42"));
+    assert_eq!(alpha.get_attribute("aria-label").as_deref(), Some("Container"));
+    assert_eq!(alpha.get_attribute("data-2x").as_deref(), Some("escaped"));
+
+    let meta = alpha.query_selector("meta")?.expect("the meta element must have been rendered");
+    assert_eq!(meta.get_attribute("http-equiv").as_deref(), Some("refresh"));
+    assert_eq!(meta.get_attribute("content").as_deref(), Some("30"));
+
+    // Prefixed attributes are created inside their namespace
+    let link = alpha.query_selector("a")?.expect("the link must have been rendered");
+    let href = link.get_attribute_node_ns(Some("http://www.w3.org/1999/xlink"), "href").expect("the xlink attribute must have been set");
+    assert_eq!(href.value(), "#target");
+    assert_eq!(href.prefix().as_deref(), Some("xlink"));
+    assert_eq!(href.name(), "xlink:href");
+    assert!(!link.has_attribute("href"));
+
+    assert!(link.class_list().contains("is-active"));
+    let style = link.unchecked_into::<web_sys::HtmlElement>().style();
+    assert_eq!(style.get_property_value("background-color")?, "red");
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn spread_attributes () -> Result<()> {
+    use std::collections::BTreeMap;
+
+    let mut attrs = Cell::new(BTreeMap::from([
+        ("data-state", String::from("idle")),
+        ("title", String::from("Spread"))
+    ]));
+
+    let alpha = html! {
+        <div id={"spread"} {..[("data-kind", "static"), ("role", "note")]} {..&attrs}>
+            {"Spread"}
+        </div>
+    }?;
+
+    append_body(alpha)?;
+    attrs.set(BTreeMap::from([("data-state", String::from("busy"))]));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn spread_attribute_removal () -> Result<()> {
+    use std::collections::BTreeMap;
+
+    let mut attrs = Cell::new(BTreeMap::from([
+        ("hidden", true),
+        ("disabled", true)
+    ]));
+
+    let alpha = html! {
+        <button {..&attrs}>"Toggle"</button>
+    }?;
+
+    let hidden = alpha.get_attribute_node("hidden").expect("the attribute must have been rendered");
+    assert!(alpha.has_attribute("disabled"));
+
+    attrs.set(BTreeMap::from([("hidden", false), ("disabled", true)]));
+    assert_eq!(alpha.get_attribute("hidden"), None);
+    assert!(alpha.has_attribute("disabled"));
+
+    attrs.set(BTreeMap::from([("hidden", true)]));
+    assert!(alpha.get_attribute_node("hidden").is_some_and(|x| x.is_same_node(Some(&hidden))));
+    assert_eq!(alpha.get_attribute("disabled"), None);
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn text () -> Result<()> {
+    let name = "world";
+    let mut count = Cell::new(3u32);
+    let price = 2.5f64;
+
+    let alpha = html! {
+        <div>
+            "Hello " {name} "!"
+            <p>{f "Hello {name}, you have {count} items ({price:.2} each) {{escaped}}"}</p>
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Hello world!Hello world, you have 3 items (2.50 each) {escaped}"));
+    let paragraph = alpha.last_element_child().expect("the paragraph must have been rendered");
+    let node = paragraph.first_child().expect("the interpolation must have been rendered");
+    assert!(node.next_sibling().is_none());
+
+    // Only the existing text node's data changes
+    count.set(4);
+    assert_eq!(paragraph.text_content().as_deref(), Some("Hello world, you have 4 items (2.50 each) {escaped}"));
+    assert!(node.next_sibling().is_none());
+    assert!(paragraph.first_child().is_some_and(|x| x.is_same_node(Some(&node))));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn control_flow () -> Result<()> {
+    #[derive(Clone)]
+    struct Todo {
+        id: u32,
+        title: &'static str
+    }
+
+    let mut visible = Cell::new(true);
+    let mut count = Cell::new(Some(1u32));
+    let mut todos = Cell::new(vec![Todo { id: 1, title: "Alpha" }, Todo { id: 2, title: "Beta" }]);
+    let admin = false;
+    let role = String::from("user");
+    let hidden_renders = std::rc::Rc::new(std::cell::Cell::new(0u32));
+    let my_hidden_renders = hidden_renders.clone();
+
+    let alpha = html! {
+        <div>
+            if visible {
+                <p>"Visible"</p>
+            } else {
+                <p>{{ my_hidden_renders.set(my_hidden_renders.get() + 1); "Hidden" }}</p>
+            }
+
+            if admin {
+                "Admin"
+            } else if role == "user" {
+                "User"
+            }
+
+            match count {
+                Some(0) | None => { "Empty" }
+                Some(x) if *x > 10 => { "Many" }
+                Some(x) => { <span>{*x}</span> " items" }
+            }
+
+            <ul>
+                for todo in &todos key={todo.id} {
+                    <li>{todo.title}</li>
+                }
+            </ul>
+
+            <ol>
+                for i in 0..3 {
+                    <li>{i}</li>
+                }
+            </ol>
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("VisibleUser1 itemsAlphaBeta012"));
+    assert_eq!(hidden_renders.get(), 0);
+
+    visible.set(false);
+    assert_eq!(alpha.text_content().as_deref(), Some("HiddenUser1 itemsAlphaBeta012"));
+    assert_eq!(hidden_renders.get(), 1);
+
+    count.set(Some(20));
+    assert_eq!(alpha.text_content().as_deref(), Some("HiddenUserManyAlphaBeta012"));
+
+    todos.set(vec![Todo { id: 2, title: "Beta" }, Todo { id: 3, title: "Gamma" }]);
+    assert_eq!(alpha.text_content().as_deref(), Some("HiddenUserManyBetaGamma012"));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[component]
+fn Card (title: &'static str, children: Children) -> Result<Element> {
+    html! {
+        <div class={"card"}>
+            <h2>{title}</h2>
+            {children}
+        </div>
+    }
+}
+
+#[component]
+fn Layout (header: Children, footer: Children, children: Children) -> Result<Element> {
+    html! {
+        <div class={"layout"}>
+            <header>{header}</header>
+            <main>{&children}{&children}</main>
+            <footer>{footer}</footer>
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+pub fn children () -> Result<()> {
+    let count = SharedCell::new(0u32);
+    let my_count = count.clone();
+
+    let alpha = html! {
+        <Layout>
+            slot header {
+                <h1>"Title"</h1>
+            }
+
+            <Card title={"Static"}>
+                <p>"Rendered twice"</p>
+            </Card>
+
+            slot footer {
+                "Footer"
+            }
+        </Layout>
+    }?;
+
+    let beta = html! {
+        <Card title={"Counter"}>
+            <p>"Count: " {&my_count}</p>
+        </Card>
+    }?;
+
+    append_body(beta.render()?)?;
+    append_body(alpha.render()?)?;
+    dynui::cell::RefMutableCell::set(&count, 1);
+    Ok(())
+}
+
+#[component]
+fn Panel (children: ChildrenOnce) -> Result<Element> {
+    html! {
+        <section>{children}</section>
+    }
+}
+
+#[wasm_bindgen_test]
+pub fn children_once () -> Result<()> {
+    let name = String::from("Nested");
+    let alpha = html! {
+        <div>
+            <Panel>
+                <Panel>
+                    <p>{name}</p>
+                </Panel>
+            </Panel>
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Nested"));
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[component]
+fn Badge (
+    #[prop(into)] label: String,
+    #[prop(default = 1)] count: u32,
+    #[prop(optional)] color: Option<&'static str>,
+    #[prop(default)] outlined: bool
+) -> Result<Element> {
+    html! {
+        <span class:outlined={outlined} style:color={color}>{label} " (" {count} ")"</span>
+    }
+}
+
+#[wasm_bindgen_test]
+pub fn default_props () -> Result<()> {
+    use dynui::lib::input::Button;
+
+    let built = Badge::builder().label("Built").build();
+    assert_eq!(built.label, "Built");
+    assert_eq!(built.count, 1);
+    assert_eq!(built.color, None);
+    assert!(!built.outlined);
+
+    let alpha = html! {
+        <div>
+            <Badge label={"Inbox"} />
+            <Badge label={String::from("Sent")} count={4} color={"red"} outlined={true} />
+            <Button text={"Click me!"} onclick={|_| jsprintln!("Clicked")} />
+        </div>
+    }?;
+
+    let badge = |i| alpha.children().item(i).expect("the badge must have been rendered").unchecked_into::<web_sys::HtmlElement>();
+
+    let inbox = badge(0);
+    assert_eq!(inbox.text_content().as_deref(), Some("Inbox (1)"));
+    assert!(!inbox.class_list().contains("outlined"));
+    assert_eq!(inbox.style().get_property_value("color")?, "");
+
+    let sent = badge(1);
+    assert_eq!(sent.text_content().as_deref(), Some("Sent (4)"));
+    assert!(sent.class_list().contains("outlined"));
+    assert_eq!(sent.style().get_property_value("color")?, "red");
+
+    append_body(alpha)?;
+    append_body(built.render()?)?;
+    Ok(())
+}
+
+#[component(fallback = "Loading profile")]
+async fn Profile (id: u32) -> Result<Element> {
+    dynui::lib::r#async::sleep(Duration::from_millis(10)).await?;
+    html! {
+        <p>{f "Profile #{id}"}</p>
+    }
+}
+
+#[component]
+async fn Greeting (name: &'static str) -> Result<Node> {
+    dynui::lib::r#async::sleep(Duration::from_millis(5)).await?;
+    html! { "Hello, " {name} }
+}
+
+#[wasm_bindgen_test]
+pub async fn async_components () -> Result<()> {
+    use dynui::lib::r#async::{Suspense, sleep};
+
+    let alpha = html! {
+        <div>
+            <Profile id={1} />
+            <Suspense children={|| html! { <Greeting name={"world"} /> }} fallback={"Waiting"} />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Loading profileWaiting"));
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(alpha.text_content().as_deref(), Some("Profile #1Hello, world"));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[derive(Component)]
+struct Counter {
+    label: &'static str,
+    #[prop(default = 1)]
+    step: u32,
+    #[prop(skip, default = SharedCell::new(0))]
+    count: SharedCell<u32>
+}
+
+impl Counter {
+    fn increment (&self) -> impl 'static + FnMut(web_sys::Event) {
+        let count = self.count.clone();
+        let step = self.step;
+        move |_| dynui::cell::RefMutableCell::mutate(&count, |x| x.add_assign(step))
+    }
+
+    fn view (&self) -> impl '_ + Component {
+        html! {
+            <button on:click={self.increment()}>{self.label} ": " {&self.count}</button>
+        }
+    }
+}
+
+#[derive(Component)]
+struct Computed<F> where F: Fn() -> u32 {
+    compute: F
+}
+
+impl<F> Computed<F> where F: Fn() -> u32 {
+    fn view (&self) -> impl '_ + Component {
+        html! { <span>{(self.compute)()}</span> }
+    }
+}
+
+#[wasm_bindgen_test]
+pub fn struct_components () -> Result<()> {
+    let counter = Counter::builder().label("Built").step(2).build();
+    assert_eq!(counter.label, "Built");
+    assert_eq!(counter.step, 2);
+    assert_eq!(*counter.count.get(), 0);
+
+    let alpha = html! {
+        <div>
+            <Counter label={"Clicks"} />
+            {&counter}
+            {&counter}
+            <Computed compute={|| 6 * 7} />
+        </div>
+    }?;
+
+    assert_eq!(alpha.text_content().as_deref(), Some("Clicks: 0Built: 0Built: 042"));
+
+    // Both renders of `counter` share it's state
+    let button = alpha.children().item(1).expect("the counter must have been rendered");
+    button.unchecked_into::<web_sys::HtmlElement>().click();
+    assert_eq!(*counter.count.get(), 2);
+    assert_eq!(alpha.text_content().as_deref(), Some("Clicks: 0Built: 2Built: 242"));
+
+    append_body(alpha)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn typed_elements () -> Result<()> {
+    let input: Element<web_sys::HtmlInputElement> = html! {
+        <input r#type={"text"} value={"Alpha"} />
+    }?;
+
+    assert_eq!(input.value(), "Alpha");
+    input.set_value("Beta");
+    assert_eq!(input.tag_name(), "INPUT");
+
+    let untyped = input.clone().into_untyped();
+    let input = match untyped.dyn_into::<web_sys::HtmlInputElement>() {
+        Ok(x) => x,
+        Err(_) => panic!("expected an input element")
+    };
+    assert_eq!(input.value(), "Beta");
+
+    let heading: Element<web_sys::HtmlHeadingElement> = html! { <h1>"Title"</h1> }?;
+    heading.set_align("center");
+
+    fn paragraph () -> Result<Element> {
+        return Ok(html! { <p>"Untyped"</p> }?.into())
+    }
+
+    let paragraph = paragraph()?;
+    let node: &Node = &paragraph;
+    node.append_child(html! { " paragraph" }?)?;
+
+    append_body(input)?;
+    append_body(heading)?;
+    append_body(paragraph)?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn node_refs () -> Result<()> {
+    let input = NodeRef::<web_sys::HtmlInputElement>::new();
+    let container = NodeRef::<web_sys::HtmlElement>::new();
+    assert!(input.get().is_none());
+
+    let focused = SharedCell::new(false);
+    let my_focused = focused.clone();
+    input.on_set(move |x| {
+        x.set_value("Focused");
+        dynui::cell::RefMutableCell::set(&my_focused, true)
+    });
+
+    let alpha = html! {
+        <div ref={container}>
+            <input r#type={"text"} ref={input} />
+        </div>
+    }?;
+
+    append_body(alpha)?;
+    assert!(*focused.get());
+    assert_eq!(container.get().map(|x| x.tag_name()).as_deref(), Some("DIV"));
+
+    let input = input.get().expect("the input must have been created");
+    input.focus()?;
+    assert_eq!(input.value(), "Focused");
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+pub fn namespaces () -> Result<()> {
+    const SVG: &str = "http://www.w3.org/2000/svg";
+    let radius = SharedCell::new(10u32);
+    let ratio = SharedCell::new("none");
+
+    let icon: Element<web_sys::SvgsvgElement> = html! {
+        <svg width={"100"} height={"100"} viewBox={"0 0 100 100"} preserveAspectRatio={&ratio}>
+            <defs>
+                <circle id={"dot"} cx={"50"} cy={"50"} r={&radius} fill={"red"} />
+            </defs>
+            <use xlink:href={"#dot"} />
+            <foreignObject width={"100"} height={"20"}>
+                <div>"HTML inside SVG"</div>
+            </foreignObject>
+        </svg>
+    }?;
+
+    assert_eq!(icon.namespace_uri().as_deref(), Some(SVG));
+    assert_eq!(icon.get_attribute("viewBox").as_deref(), Some("0 0 100 100"));
+    assert_eq!(icon.get_attribute("preserveAspectRatio").as_deref(), Some("none"));
+    assert!(!icon.has_attribute("preserveaspectratio"));
+
+    dynui::cell::RefMutableCell::set(&ratio, "xMidYMid meet");
+    assert_eq!(icon.get_attribute("preserveAspectRatio").as_deref(), Some("xMidYMid meet"));
+
+    let circle = icon.query_selector("circle")?.expect("the circle must have been created");
+    assert_eq!(circle.namespace_uri().as_deref(), Some(SVG));
+
+    let link = icon.query_selector("use")?.expect("the use element must have been created");
+    assert_eq!(link.get_attribute_ns(Some("http://www.w3.org/1999/xlink"), "href").as_deref(), Some("#dot"));
+
+    let div = icon.query_selector("div")?.expect("the div must have been created");
+    assert_eq!(div.namespace_uri().as_deref(), Some("http://www.w3.org/1999/xhtml"));
+
+    let formula = html! {
+        <math><mi>"x"</mi><mo>"="</mo><mn>"2"</mn></math>
+    }?;
+    assert_eq!(formula.namespace_uri().as_deref(), Some("http://www.w3.org/1998/Math/MathML"));
+
+    append_body(icon)?;
+    append_body(formula)?;
+    dynui::cell::RefMutableCell::set(&radius, 20);
+    Ok(())
+}